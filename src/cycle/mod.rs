@@ -0,0 +1,80 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// Number of steps taken before the loop is entered
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Smallest step count that leads to the same state as `n` steps
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// State after applying `step` `n` times, remembering every state seen so that the remaining
+/// steps can be skipped once a state repeats. The cycle is `None` if no state repeated within
+/// `n` steps.
+pub fn fast_forward<S: Clone + Hash + Eq>(
+    initial: S,
+    n: usize,
+    step: impl Fn(&S) -> S,
+) -> (S, Option<Cycle>) {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+
+    for i in 0..n {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix,
+                period: i - prefix,
+            };
+            return (history.swap_remove(cycle.reduce(n)), Some(cycle));
+        }
+        seen.insert(state.clone(), i);
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+
+    (state, None)
+}
+
+/// Brent's cycle detection, keeping only two states in memory. Never returns if the sequence
+/// of states does not repeat.
+pub fn brent<S: Clone + Eq>(initial: S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
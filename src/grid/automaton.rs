@@ -0,0 +1,184 @@
+use crate::grid::{GridMut, Neighborhood};
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Display, Formatter, Result},
+    mem,
+    str::FromStr,
+};
+
+/// Steps a grid as a cellular automaton, where present cells are live. Each generation is written
+/// into a second buffer that is swapped in afterwards, so rules always see the previous
+/// generation.
+#[derive(Clone, Debug)]
+pub struct Automaton<G> {
+    current: G,
+    next: G,
+    neighborhood: Neighborhood,
+    generation: usize,
+}
+
+impl<G: GridMut + Clone> Automaton<G>
+where
+    G::Value: Clone,
+{
+    pub fn new(grid: G, neighborhood: Neighborhood) -> Self {
+        let mut next = grid.clone();
+        next.indexed_retain(|_, _| false);
+        Self {
+            current: grid,
+            next,
+            neighborhood,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &G {
+        &self.current
+    }
+
+    pub fn into_inner(self) -> G {
+        self.current
+    }
+
+    /// Number of steps taken so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances one generation. `rule` receives the current value of every live cell and every
+    /// cell adjacent to one, along with the number of live neighbors, and returns the next value.
+    /// Panics on grids with a background, where every cell is live and the count is meaningless;
+    /// use [`Self::step_with`] and count the neighbors by value instead.
+    pub fn step(&mut self, rule: impl Fn(Option<&G::Value>, usize) -> Option<G::Value>) {
+        assert!(
+            self.current.background().is_none(),
+            "step counts present neighbors, use step_with on grids with a background"
+        );
+        self.step_with(|_, v, neighbors| rule(v, neighbors.len()));
+    }
+
+    /// Like [`Self::step`], but `rule` also receives the position and the values of the live
+    /// neighbors, for automata with more than one live state. On grids with a background, such as
+    /// [`InfiniteGrid`](crate::grid::infinite::InfiniteGrid), every cell is live and the
+    /// background is stepped as well, keeping its value where `rule` returns `None`.
+    pub fn step_with(
+        &mut self,
+        rule: impl Fn(&G::Point, Option<&G::Value>, &[&G::Value]) -> Option<G::Value>,
+    ) {
+        let candidates: HashSet<G::Point> = self
+            .current
+            .indexed_iter()
+            .flat_map(|(p, _)| {
+                let mut cells = self.current.adjacent(&p, self.neighborhood);
+                cells.push(p);
+                cells
+            })
+            .collect();
+
+        self.next.indexed_retain(|_, _| false);
+        if let Some((p, background)) = self.current.background() {
+            let neighbors: Vec<_> = self.current.neighbors(&p, self.neighborhood).collect();
+            let next = rule(&p, Some(background), &neighbors).unwrap_or_else(|| background.clone());
+            self.next.set_background(next);
+        }
+        for p in candidates {
+            let neighbors: Vec<_> = self.current.neighbors(&p, self.neighborhood).collect();
+            if let Some(v) = rule(&p, self.current.get(&p), &neighbors) {
+                self.next.set(p, v);
+            }
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    /// Takes `n` steps with [`Self::step`], so it panics on grids with a background as well
+    pub fn run(&mut self, n: usize, rule: impl Fn(Option<&G::Value>, usize) -> Option<G::Value>) {
+        for _ in 0..n {
+            self.step(&rule);
+        }
+    }
+}
+
+/// Birth and survival neighbor counts of a Life-like automaton, written as e.g. `B3/S23`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LifeRule {
+    pub birth: Vec<usize>,
+    pub survival: Vec<usize>,
+}
+
+impl LifeRule {
+    pub fn new(birth: Vec<usize>, survival: Vec<usize>) -> Self {
+        Self { birth, survival }
+    }
+
+    /// Conway's Game of Life, `B3/S23`
+    pub fn conway() -> Self {
+        Self::new(vec![3], vec![2, 3])
+    }
+
+    /// Whether a cell with `live` live neighbors is alive in the next generation
+    pub fn alive_next(&self, alive: bool, live: usize) -> bool {
+        if alive {
+            self.survival.contains(&live)
+        } else {
+            self.birth.contains(&live)
+        }
+    }
+
+    /// Next value of a cell with `live` live neighbors, using `alive` for cells that are born
+    pub fn next<T: Clone>(&self, current: Option<&T>, live: usize, alive: &T) -> Option<T> {
+        self.alive_next(current.is_some(), live)
+            .then(|| current.unwrap_or(alive).clone())
+    }
+}
+
+/// A rule string that is not of the form `B<digits>/S<digits>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLifeRuleError {
+    pub rule: String,
+}
+
+impl Display for ParseLifeRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "invalid life rule {:?}", self.rule)
+    }
+}
+
+impl Error for ParseLifeRuleError {}
+
+impl FromStr for LifeRule {
+    type Err = ParseLifeRuleError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let err = || ParseLifeRuleError {
+            rule: s.to_string(),
+        };
+        let counts = |part: &str, prefix: char| -> Option<Vec<usize>> {
+            part.strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))?
+                .chars()
+                .map(|ch| ch.to_digit(10).map(|d| d as usize))
+                .collect()
+        };
+        let (birth, survival) = s.trim().split_once('/').ok_or_else(err)?;
+        Ok(Self {
+            birth: counts(birth, 'B').ok_or_else(err)?,
+            survival: counts(survival, 'S').ok_or_else(err)?,
+        })
+    }
+}
+
+impl Display for LifeRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "B")?;
+        for n in &self.birth {
+            write!(f, "{}", n)?;
+        }
+        write!(f, "/S")?;
+        for n in &self.survival {
+            write!(f, "{}", n)?;
+        }
+        Ok(())
+    }
+}
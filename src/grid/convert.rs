@@ -0,0 +1,150 @@
+use crate::{
+    grid::{dynamic_2d::Dynamic2DGrid, static_2d::Static2DGrid},
+    grid_point::signed::GridPoint,
+};
+use ndarray::Array2;
+
+impl<T> Dynamic2DGrid<T> {
+    /// Dense copy covering exactly [`Self::bounds`], with the offset to add to a dense
+    /// point to get back the sparse one
+    pub fn into_static(self) -> (Static2DGrid<T>, GridPoint) {
+        let Some((min, max)) = self.bounds() else {
+            return (Static2DGrid::new(0, 0), GridPoint::new(0, 0));
+        };
+        let mut grid =
+            Static2DGrid::new((max.r - min.r + 1) as usize, (max.c - min.c + 1) as usize);
+        for (p, v) in self.data {
+            grid.set((p.r - min.r) as usize, (p.c - min.c) as usize, v);
+        }
+        (grid, min)
+    }
+}
+
+impl<T> Static2DGrid<T> {
+    /// Sparse copy holding only present cells, each moved by `offset`
+    pub fn into_dynamic(self, offset: GridPoint) -> Dynamic2DGrid<T> {
+        let data = self
+            .into_indexed_iter()
+            .filter_map(|(r, c, v)| Some((offset + GridPoint::new(r as isize, c as isize), v?)))
+            .collect();
+        Dynamic2DGrid::new(data)
+    }
+
+    /// Plain array with `fill` in place of absent cells
+    pub fn into_array(self, fill: T) -> Array2<T>
+    where
+        T: Clone,
+    {
+        let shape = self.data.raw_dim();
+        let cells = self
+            .data
+            .into_iter()
+            .map(|v| v.unwrap_or_else(|| fill.clone()));
+        Array2::from_shape_vec(shape, cells.collect()).unwrap()
+    }
+}
+
+impl<T> From<Static2DGrid<T>> for Dynamic2DGrid<T> {
+    fn from(grid: Static2DGrid<T>) -> Self {
+        grid.into_dynamic(GridPoint::new(0, 0))
+    }
+}
+
+/// Keeps every point where it is, so it fails and hands the grid back if any point has a
+/// negative coordinate. Use [`Dynamic2DGrid::into_static`] to shift the grid instead.
+impl<T> TryFrom<Dynamic2DGrid<T>> for Static2DGrid<T> {
+    type Error = Dynamic2DGrid<T>;
+
+    fn try_from(grid: Dynamic2DGrid<T>) -> Result<Self, Self::Error> {
+        let Some((min, max)) = grid.bounds() else {
+            return Ok(Static2DGrid::new(0, 0));
+        };
+        if min.r < 0 || min.c < 0 {
+            return Err(grid);
+        }
+        let mut result = Static2DGrid::new(max.r as usize + 1, max.c as usize + 1);
+        for (p, v) in grid.data {
+            result.set(p.r as usize, p.c as usize, v);
+        }
+        Ok(result)
+    }
+}
+
+impl<T> From<Array2<Option<T>>> for Static2DGrid<T> {
+    fn from(data: Array2<Option<T>>) -> Self {
+        Self { data }
+    }
+}
+
+impl<T> From<Static2DGrid<T>> for Array2<Option<T>> {
+    fn from(grid: Static2DGrid<T>) -> Self {
+        grid.data
+    }
+}
+
+/// Fails and hands the grid back if any cell is absent
+impl<T> TryFrom<Static2DGrid<T>> for Array2<T> {
+    type Error = Static2DGrid<T>;
+
+    fn try_from(grid: Static2DGrid<T>) -> Result<Self, Self::Error> {
+        if grid.iter().any(|v| v.is_none()) {
+            return Err(grid);
+        }
+        let shape = grid.data.raw_dim();
+        let cells = grid.data.into_iter().map(Option::unwrap);
+        Ok(Array2::from_shape_vec(shape, cells.collect()).unwrap())
+    }
+}
+
+/// Rows shorter than the longest one are padded with absent cells
+impl<T> From<Vec<Vec<T>>> for Static2DGrid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        rows.into_iter()
+            .map(|row| row.into_iter().map(Some).collect())
+            .collect::<Vec<Vec<Option<T>>>>()
+            .into()
+    }
+}
+
+/// Rows shorter than the longest one are padded with absent cells, so only rows of equal length
+/// convert back unchanged
+impl<T> From<Vec<Vec<Option<T>>>> for Static2DGrid<T> {
+    fn from(rows: Vec<Vec<Option<T>>>) -> Self {
+        let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut grid = Static2DGrid::new(rows.len(), cols);
+        for (r, row) in rows.into_iter().enumerate() {
+            for (c, v) in row.into_iter().enumerate() {
+                grid.data[(r, c)] = v;
+            }
+        }
+        grid
+    }
+}
+
+impl<T> From<Static2DGrid<T>> for Vec<Vec<Option<T>>> {
+    fn from(grid: Static2DGrid<T>) -> Self {
+        let mut rows: Vec<Vec<Option<T>>> = (0..grid.rows())
+            .map(|_| Vec::with_capacity(grid.columns()))
+            .collect();
+        for (r, _, v) in grid.into_indexed_iter() {
+            rows[r].push(v);
+        }
+        rows
+    }
+}
+
+/// Fails and hands the grid back if any cell is absent
+impl<T> TryFrom<Static2DGrid<T>> for Vec<Vec<T>> {
+    type Error = Static2DGrid<T>;
+
+    fn try_from(grid: Static2DGrid<T>) -> Result<Self, Self::Error> {
+        if grid.iter().any(|v| v.is_none()) {
+            return Err(grid);
+        }
+        let rows: Vec<Vec<Option<T>>> = grid.into();
+        Ok(rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Option::unwrap).collect())
+            .collect())
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    grid::static_2d::Static2DGrid,
+    grid_point::{direction::Direction, signed::GridPoint, unsigned::UGridPoint},
+};
+use std::collections::{HashMap, VecDeque};
+
+type Vec3 = [isize; 3];
+
+fn neg(a: Vec3) -> Vec3 {
+    a.map(|x| -x)
+}
+
+fn dot(a: Vec3, b: Vec3) -> isize {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: Vec3, k: isize) -> Vec3 {
+    a.map(|x| x * k)
+}
+
+/// Placement of one face on the folded cube. `right` and `down` are the 3D directions of the
+/// face's column and row axes, `normal` points out of the cube.
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    origin: UGridPoint,
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    fn axis(&self, d: Direction) -> Vec3 {
+        match d {
+            Direction::North => neg(self.down),
+            Direction::East => self.right,
+            Direction::South => self.down,
+            Direction::West => neg(self.right),
+        }
+    }
+}
+
+/// A 2D map that is the unfolded net of a cube. Present cells belong to the cube's faces, absent
+/// cells lie outside the net.
+#[derive(Clone, Debug)]
+pub struct CubeNet {
+    pub size: usize,
+    faces: Vec<Face>,
+    /// Face index by the face's position in units of `size`
+    layout: HashMap<(usize, usize), usize>,
+}
+
+impl CubeNet {
+    /// Detects the six faces and folds them, or returns `None` if the present cells do not
+    /// form a valid cube net
+    pub fn new<T>(grid: &Static2DGrid<T>) -> Option<Self> {
+        let cells = grid.iter().filter(|v| v.is_some()).count();
+        let size = (cells / 6).isqrt();
+        if size == 0 || size * size * 6 != cells {
+            return None;
+        }
+
+        let mut layout = HashMap::new();
+        for fr in 0..grid.rows() / size {
+            for fc in 0..grid.columns() / size {
+                if grid.get(fr * size, fc * size).is_some() {
+                    layout.insert((fr, fc), layout.len());
+                }
+            }
+        }
+        if layout.len() != 6 {
+            return None;
+        }
+        let full = layout.keys().all(|&(fr, fc)| {
+            (0..size).all(|r| (0..size).all(|c| grid.get(fr * size + r, fc * size + c).is_some()))
+        });
+        if !full {
+            return None;
+        }
+
+        let first = *layout.iter().find(|&(_, &i)| i == 0)?.0;
+        let mut faces: Vec<Option<Face>> = vec![None; 6];
+        faces[0] = Some(Face {
+            origin: UGridPoint::new(first.0 * size, first.1 * size),
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        });
+        let mut queue = VecDeque::from([first]);
+        while let Some((fr, fc)) = queue.pop_front() {
+            let face = faces[layout[&(fr, fc)]]?;
+            let folds = [
+                (fr.checked_sub(1).map(|r| (r, fc)), Direction::North),
+                (Some((fr + 1, fc)), Direction::South),
+                (fc.checked_sub(1).map(|c| (fr, c)), Direction::West),
+                (Some((fr, fc + 1)), Direction::East),
+            ];
+            for (pos, d) in folds {
+                let Some(pos) = pos else { continue };
+                let Some(&i) = layout.get(&pos) else { continue };
+                if faces[i].is_some() {
+                    continue;
+                }
+                let normal = face.axis(d);
+                let (right, down) = match d {
+                    Direction::North => (face.right, face.normal),
+                    Direction::South => (face.right, neg(face.normal)),
+                    Direction::West => (face.normal, face.down),
+                    Direction::East => (neg(face.normal), face.down),
+                };
+                faces[i] = Some(Face {
+                    origin: UGridPoint::new(pos.0 * size, pos.1 * size),
+                    normal,
+                    right,
+                    down,
+                });
+                queue.push_back(pos);
+            }
+        }
+
+        let faces: Vec<Face> = faces.into_iter().collect::<Option<_>>()?;
+        let distinct = (0..6).all(|i| (0..i).all(|j| faces[i].normal != faces[j].normal));
+        distinct.then_some(Self {
+            size,
+            faces,
+            layout,
+        })
+    }
+
+    /// Index of the face containing `p`, numbered in reading order of the net
+    pub fn face_of(&self, p: UGridPoint) -> Option<usize> {
+        self.layout
+            .get(&(p.r / self.size, p.c / self.size))
+            .copied()
+    }
+
+    /// Moves one cell from `p` towards `heading`. Walking off a face continues on the face that
+    /// borders it on the folded cube, with the heading turned to match.
+    pub fn step(&self, p: UGridPoint, heading: Direction) -> (UGridPoint, Direction) {
+        let face = self.faces[self.face_of(p).expect("point lies outside the cube net")];
+        let next = GridPoint::from(p) + heading.delta();
+        if next.r >= 0 && next.c >= 0 {
+            let next = UGridPoint::new(next.r as usize, next.c as usize);
+            if self.face_of(next).is_some() {
+                return (next, heading);
+            }
+        }
+
+        // Cell centres in doubled coordinates on a cube spanning -size..=size on every axis
+        let n = self.size as isize;
+        let (i, j) = (
+            (p.r - face.origin.r) as isize,
+            (p.c - face.origin.c) as isize,
+        );
+        let point = add(
+            scale(face.normal, n),
+            add(
+                scale(face.right, 2 * j - n + 1),
+                scale(face.down, 2 * i - n + 1),
+            ),
+        );
+        let target_normal = face.axis(heading);
+        let target = *self
+            .faces
+            .iter()
+            .find(|f| f.normal == target_normal)
+            .unwrap();
+        let point = add(add(point, target_normal), neg(face.normal));
+
+        let j = (dot(point, target.right) + n - 1) / 2;
+        let i = (dot(point, target.down) + n - 1) / 2;
+        let inward = neg(face.normal);
+        let heading = Direction::ALL
+            .into_iter()
+            .find(|&d| target.axis(d) == inward)
+            .unwrap();
+        (
+            UGridPoint::new(target.origin.r + i as usize, target.origin.c + j as usize),
+            heading,
+        )
+    }
+}
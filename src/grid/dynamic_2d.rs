@@ -1,13 +1,18 @@
-use crate::grid_point::signed::GridPoint;
+use crate::{
+    grid::{Grid, GridMut, Neighborhood, sorted_entries, sparse_hash},
+    grid_point::signed::GridPoint,
+};
 use std::{
+    cmp::Ordering,
     collections::{
         HashMap,
         hash_map::{IntoValues, Values, ValuesMut},
     },
     fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
 };
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Dynamic2DGrid<T> {
     pub data: HashMap<GridPoint, T>,
 }
@@ -184,6 +189,66 @@ impl<T> Dynamic2DGrid<T> {
     }
 }
 
+impl<T> Grid for Dynamic2DGrid<T> {
+    type Point = GridPoint;
+    type Value = T;
+
+    fn get(&self, p: &GridPoint) -> Option<&T> {
+        self.data.get(p)
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        self.data.iter().map(|(p, v)| (*p, v))
+    }
+
+    fn adjacent(&self, p: &GridPoint, neighborhood: Neighborhood) -> Vec<GridPoint> {
+        match neighborhood {
+            Neighborhood::Cardinal => p.cardinal_neighbors().to_vec(),
+            Neighborhood::All => p.all_neighbors().to_vec(),
+        }
+    }
+}
+
+impl<T> GridMut for Dynamic2DGrid<T> {
+    fn get_mut(&mut self, p: &GridPoint) -> Option<&mut T> {
+        self.data.get_mut(p)
+    }
+
+    fn set(&mut self, p: GridPoint, v: T) -> Option<T> {
+        self.data.insert(p, v)
+    }
+
+    fn remove(&mut self, p: &GridPoint) -> Option<T> {
+        self.data.remove(p)
+    }
+
+    fn indexed_retain(&mut self, f: impl Fn(GridPoint, Option<&T>) -> bool) {
+        Dynamic2DGrid::indexed_retain(self, f);
+    }
+
+    fn indexed_update(&mut self, f: impl Fn(GridPoint, Option<&T>) -> Option<T>) {
+        Dynamic2DGrid::indexed_update(self, f);
+    }
+}
+
+impl<T: Hash> Hash for Dynamic2DGrid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        sparse_hash(&self.data, state);
+    }
+}
+
+impl<T: Ord> Ord for Dynamic2DGrid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sorted_entries(&self.data).cmp(&sorted_entries(&other.data))
+    }
+}
+
+impl<T: Ord> PartialOrd for Dynamic2DGrid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<T> Default for Dynamic2DGrid<T> {
     fn default() -> Self {
         Self {
@@ -218,15 +283,7 @@ impl<T> IntoIterator for Dynamic2DGrid<T> {
 
 impl From<&str> for Dynamic2DGrid<char> {
     fn from(s: &str) -> Self {
-        let mut data = HashMap::new();
-        for (r, line) in s.lines().enumerate() {
-            for (c, ch) in line.chars().enumerate() {
-                if ch != ' ' {
-                    data.insert(GridPoint::new(r as isize, c as isize), ch);
-                }
-            }
-        }
-        Dynamic2DGrid { data }
+        Dynamic2DGrid::parse_with(s, |ch| (ch != ' ').then_some(ch))
     }
 }
 
@@ -1,10 +1,17 @@
-use crate::grid_point::hex::HexGridPoint;
-use std::collections::{
-    HashMap,
-    hash_map::{IntoValues, Values, ValuesMut},
+use crate::{
+    grid::{Grid, GridMut, Neighborhood, sorted_entries, sparse_hash},
+    grid_point::hex::HexGridPoint,
+};
+use std::{
+    cmp::Ordering,
+    collections::{
+        HashMap,
+        hash_map::{IntoValues, Values, ValuesMut},
+    },
+    hash::{Hash, Hasher},
 };
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HexGrid<T> {
     pub data: HashMap<HexGridPoint, T>,
 }
@@ -174,6 +181,63 @@ impl<T> HexGrid<T> {
     }
 }
 
+impl<T> Grid for HexGrid<T> {
+    type Point = HexGridPoint;
+    type Value = T;
+
+    fn get(&self, p: &HexGridPoint) -> Option<&T> {
+        self.data.get(p)
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (HexGridPoint, &T)> {
+        self.data.iter().map(|(p, v)| (*p, v))
+    }
+
+    fn adjacent(&self, p: &HexGridPoint, _neighborhood: Neighborhood) -> Vec<HexGridPoint> {
+        p.neighbors().to_vec()
+    }
+}
+
+impl<T> GridMut for HexGrid<T> {
+    fn get_mut(&mut self, p: &HexGridPoint) -> Option<&mut T> {
+        self.data.get_mut(p)
+    }
+
+    fn set(&mut self, p: HexGridPoint, v: T) -> Option<T> {
+        self.data.insert(p, v)
+    }
+
+    fn remove(&mut self, p: &HexGridPoint) -> Option<T> {
+        self.data.remove(p)
+    }
+
+    fn indexed_retain(&mut self, f: impl Fn(HexGridPoint, Option<&T>) -> bool) {
+        HexGrid::indexed_retain(self, f);
+    }
+
+    fn indexed_update(&mut self, f: impl Fn(HexGridPoint, Option<&T>) -> Option<T>) {
+        HexGrid::indexed_update(self, f);
+    }
+}
+
+impl<T: Hash> Hash for HexGrid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        sparse_hash(&self.data, state);
+    }
+}
+
+impl<T: Ord> Ord for HexGrid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sorted_entries(&self.data).cmp(&sorted_entries(&other.data))
+    }
+}
+
+impl<T: Ord> PartialOrd for HexGrid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<T> Default for HexGrid<T> {
     fn default() -> Self {
         Self {
@@ -0,0 +1,197 @@
+use crate::{
+    grid::{Grid, GridMut, Neighborhood, dynamic_2d::Dynamic2DGrid},
+    grid_point::signed::GridPoint,
+};
+use std::{
+    array,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+};
+
+/// A sparse grid that extends forever, where every cell that isn't stored holds `background`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InfiniteGrid<T> {
+    pub data: HashMap<GridPoint, T>,
+    pub background: T,
+}
+
+impl<T> InfiniteGrid<T> {
+    pub fn new(background: T) -> Self {
+        Self {
+            data: HashMap::new(),
+            background,
+        }
+    }
+
+    pub fn from_grid(grid: Dynamic2DGrid<T>, background: T) -> Self {
+        Self {
+            data: grid.data,
+            background,
+        }
+    }
+
+    pub fn get(&self, p: &GridPoint) -> &T {
+        self.data.get(p).unwrap_or(&self.background)
+    }
+
+    pub fn set(&mut self, p: GridPoint, v: T) -> Option<T> {
+        self.data.insert(p, v)
+    }
+
+    /// Resets a cell to the background, returning the value it stored
+    pub fn remove(&mut self, p: &GridPoint) -> Option<T> {
+        self.data.remove(p)
+    }
+
+    /// Smallest rectangle containing every stored cell
+    pub fn bounds(&self) -> Option<(GridPoint, GridPoint)> {
+        let mut points = self.data.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                GridPoint::new(min.r.min(p.r), min.c.min(p.c)),
+                GridPoint::new(max.r.max(p.r), max.c.max(p.c)),
+            )
+        }))
+    }
+
+    /// Number of cells matching `f`, or `None` if the background matches and the count is
+    /// infinite
+    pub fn count(&self, f: impl Fn(&T) -> bool) -> Option<usize> {
+        (!f(&self.background)).then(|| self.data.values().filter(|v| f(v)).count())
+    }
+
+    /// The 3x3 window centred on `p` in reading order
+    pub fn window(&self, p: &GridPoint) -> [&T; 9] {
+        array::from_fn(|i| {
+            self.get(&GridPoint::new(
+                p.r + i as isize / 3 - 1,
+                p.c + i as isize % 3 - 1,
+            ))
+        })
+    }
+
+    /// Replaces every cell, including the background, with `rule` applied to the 3x3 window
+    /// around it in reading order
+    pub fn step(&mut self, rule: impl Fn([&T; 9]) -> T) {
+        let background = rule([&self.background; 9]);
+        let data = match self.bounds() {
+            Some((min, max)) => (min.r - 1..=max.r + 1)
+                .flat_map(|r| (min.c - 1..=max.c + 1).map(move |c| GridPoint::new(r, c)))
+                .map(|p| (p, rule(self.window(&p))))
+                .collect(),
+            None => HashMap::new(),
+        };
+        self.data = data;
+        self.background = background;
+    }
+
+    /// Removes stored cells that equal the background
+    pub fn normalize(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.data.retain(|_, v| *v != self.background);
+    }
+}
+
+/// Every cell holds a value, so cells outside the stored ones are never absent
+impl<T> Grid for InfiniteGrid<T> {
+    type Point = GridPoint;
+    type Value = T;
+
+    fn get(&self, p: &GridPoint) -> Option<&T> {
+        Some(InfiniteGrid::get(self, p))
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        self.data.iter().map(|(p, v)| (*p, v))
+    }
+
+    fn adjacent(&self, p: &GridPoint, neighborhood: Neighborhood) -> Vec<GridPoint> {
+        match neighborhood {
+            Neighborhood::Cardinal => p.cardinal_neighbors().to_vec(),
+            Neighborhood::All => p.all_neighbors().to_vec(),
+        }
+    }
+
+    fn background(&self) -> Option<(GridPoint, &T)> {
+        let p = self
+            .bounds()
+            .map_or(GridPoint::new(0, 0), |(_, max)| max + GridPoint::new(2, 2));
+        Some((p, &self.background))
+    }
+}
+
+impl<T: Clone> GridMut for InfiniteGrid<T> {
+    /// Stores a copy of the background first if `p` isn't stored, matching [`Grid::get`]
+    fn get_mut(&mut self, p: &GridPoint) -> Option<&mut T> {
+        Some(
+            self.data
+                .entry(*p)
+                .or_insert_with(|| self.background.clone()),
+        )
+    }
+
+    fn set(&mut self, p: GridPoint, v: T) -> Option<T> {
+        InfiniteGrid::set(self, p, v)
+    }
+
+    fn remove(&mut self, p: &GridPoint) -> Option<T> {
+        InfiniteGrid::remove(self, p)
+    }
+
+    fn set_background(&mut self, v: T) {
+        self.background = v;
+    }
+
+    fn indexed_retain(&mut self, f: impl Fn(GridPoint, Option<&T>) -> bool) {
+        self.data.retain(|p, v| f(*p, Some(v)));
+    }
+
+    fn indexed_update(&mut self, f: impl Fn(GridPoint, Option<&T>) -> Option<T>) {
+        let updates: Vec<_> = self
+            .data
+            .iter()
+            .filter_map(|(p, v)| Some((*p, f(*p, Some(v))?)))
+            .collect();
+        self.data.extend(updates);
+    }
+}
+
+/// Drawing of an [`InfiniteGrid`] with a margin of background around the stored cells
+pub struct MarginDisplay<'a, T> {
+    grid: &'a InfiniteGrid<T>,
+    margin: usize,
+}
+
+impl<T: Display> Display for MarginDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let Some((min, max)) = self.grid.bounds() else {
+            return Ok(());
+        };
+        let margin = self.margin as isize;
+
+        for r in min.r - margin..=max.r + margin {
+            for c in min.c - margin..=max.c + margin {
+                write!(f, "{}", self.grid.get(&GridPoint::new(r, c)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> InfiniteGrid<T> {
+    /// Draws the stored cells with `margin` cells of background around them
+    pub fn display_with_margin(&self, margin: usize) -> MarginDisplay<'_, T> {
+        MarginDisplay { grid: self, margin }
+    }
+}
+
+/// Draws the stored cells with a margin of one cell of background around them
+impl<T: Display> Display for InfiniteGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.display_with_margin(1).fmt(f)
+    }
+}
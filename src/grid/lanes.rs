@@ -0,0 +1,62 @@
+use crate::grid::static_2d::Static2DGrid;
+
+impl<T> Static2DGrid<T> {
+    /// Cells of row `r` from left to right
+    pub fn row(&self, r: usize) -> impl Iterator<Item = Option<&T>> {
+        self.data.row(r).into_iter().map(Option::as_ref)
+    }
+
+    /// Cells of column `c` from top to bottom
+    pub fn column(&self, c: usize) -> impl Iterator<Item = Option<&T>> {
+        self.data.column(c).into_iter().map(Option::as_ref)
+    }
+
+    pub fn row_mut(&mut self, r: usize) -> impl Iterator<Item = &mut Option<T>> {
+        self.data.row_mut(r).into_iter()
+    }
+
+    pub fn column_mut(&mut self, c: usize) -> impl Iterator<Item = &mut Option<T>> {
+        self.data.column_mut(c).into_iter()
+    }
+
+    /// Every row from top to bottom
+    pub fn rows_iter(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&T>>> {
+        self.data
+            .rows()
+            .into_iter()
+            .map(|row| row.into_iter().map(Option::as_ref))
+    }
+
+    /// Every column from left to right
+    pub fn columns_iter(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&T>>> {
+        self.data
+            .columns()
+            .into_iter()
+            .map(|column| column.into_iter().map(Option::as_ref))
+    }
+
+    pub fn rows_iter_mut(&mut self) -> impl Iterator<Item = impl Iterator<Item = &mut Option<T>>> {
+        self.data.rows_mut().into_iter().map(|row| row.into_iter())
+    }
+
+    pub fn columns_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &mut Option<T>>> {
+        self.data
+            .columns_mut()
+            .into_iter()
+            .map(|column| column.into_iter())
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for c in 0..self.columns() {
+            self.data.swap((a, c), (b, c));
+        }
+    }
+
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        for r in 0..self.rows() {
+            self.data.swap((r, a), (r, b));
+        }
+    }
+}
@@ -1,4 +1,116 @@
+pub mod automaton;
+pub mod convert;
+pub mod cube;
 pub mod dynamic_2d;
 pub mod hex;
+pub mod infinite;
+pub mod lanes;
 pub mod n_dimensional;
+pub mod obstacle;
+pub mod parse;
+pub mod pathfinding;
+pub mod pattern;
+pub mod prefix_sum;
+pub mod ray;
+pub mod reflection;
+pub mod region;
+pub mod resize;
+pub mod scale;
 pub mod static_2d;
+pub mod tilt;
+pub mod transform;
+pub mod view;
+pub mod window;
+pub mod wrapping;
+pub mod zobrist;
+
+use crate::grid::zobrist::cell_hash;
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// Cells sharing an edge: 4 in 2D, 2n in n dimensions, 6 on a hex grid
+    Cardinal,
+    /// Cells sharing an edge or a corner: 8 in 2D, 3^n - 1 in n dimensions, 6 on a hex grid
+    All,
+}
+
+pub trait Grid {
+    type Point: Clone + Eq + Hash;
+    type Value;
+
+    fn get(&self, p: &Self::Point) -> Option<&Self::Value>;
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (Self::Point, &Self::Value)>;
+
+    /// Positions next to `p` that lie inside the grid, whether or not they hold a value
+    fn adjacent(&self, p: &Self::Point, neighborhood: Neighborhood) -> Vec<Self::Point>;
+
+    /// For grids that extend forever, a cell with no stored cells around it together with the
+    /// value every cell that isn't stored holds
+    fn background(&self) -> Option<(Self::Point, &Self::Value)> {
+        None
+    }
+
+    fn contains(&self, p: &Self::Point) -> bool {
+        self.get(p).is_some()
+    }
+
+    fn neighbors(
+        &self,
+        p: &Self::Point,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = &Self::Value> {
+        self.adjacent(p, neighborhood)
+            .into_iter()
+            .filter_map(move |n| self.get(&n))
+    }
+
+    fn indexed_neighbors(
+        &self,
+        p: &Self::Point,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Self::Point> {
+        self.adjacent(p, neighborhood)
+            .into_iter()
+            .filter(move |n| self.contains(n))
+    }
+}
+
+pub trait GridMut: Grid {
+    fn get_mut(&mut self, p: &Self::Point) -> Option<&mut Self::Value>;
+
+    fn set(&mut self, p: Self::Point, v: Self::Value) -> Option<Self::Value>;
+
+    fn remove(&mut self, p: &Self::Point) -> Option<Self::Value>;
+
+    /// Replaces the value of every cell that isn't stored. Grids without a background ignore it.
+    fn set_background(&mut self, _v: Self::Value) {}
+
+    /// Removes every present cell for which `f` returns `false`. `f` is only called on present
+    /// cells, so the value is always `Some`.
+    fn indexed_retain(&mut self, f: impl Fn(Self::Point, Option<&Self::Value>) -> bool);
+
+    /// Replaces every present cell with the value `f` returns for it. `f` is only called on
+    /// present cells, and `None` leaves the cell unchanged; use [`Self::remove`] to clear it.
+    fn indexed_update(
+        &mut self,
+        f: impl Fn(Self::Point, Option<&Self::Value>) -> Option<Self::Value>,
+    );
+}
+
+/// Entries of a sparse grid in ascending point order, for order-independent comparisons
+pub(crate) fn sorted_entries<P: Ord, V>(data: &HashMap<P, V>) -> Vec<(&P, &V)> {
+    let mut entries: Vec<_> = data.iter().collect();
+    entries.sort_unstable_by_key(|&(p, _)| p);
+    entries
+}
+
+/// Hashes a sparse grid independently of the iteration order of its map
+pub(crate) fn sparse_hash<P: Hash, V: Hash, H: Hasher>(data: &HashMap<P, V>, state: &mut H) {
+    data.len().hash(state);
+    state.write_u64(data.iter().fold(0, |acc, (p, v)| acc ^ cell_hash(p, v)));
+}
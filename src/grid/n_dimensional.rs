@@ -1,9 +1,17 @@
-use crate::grid_point::n_dimensional::NDGridPoint;
-use std::collections::{
-    HashMap,
-    hash_map::{IntoValues, Values, ValuesMut},
+use crate::{
+    grid::{Grid, GridMut, Neighborhood, sorted_entries, sparse_hash},
+    grid_point::n_dimensional::NDGridPoint,
+};
+use std::{
+    cmp::Ordering,
+    collections::{
+        HashMap,
+        hash_map::{IntoValues, Values, ValuesMut},
+    },
+    hash::{Hash, Hasher},
 };
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NDGrid<T> {
     pub data: HashMap<NDGridPoint, T>,
 }
@@ -119,6 +127,66 @@ impl<T> NDGrid<T> {
     }
 }
 
+impl<T> Grid for NDGrid<T> {
+    type Point = NDGridPoint;
+    type Value = T;
+
+    fn get(&self, p: &NDGridPoint) -> Option<&T> {
+        self.data.get(p)
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (NDGridPoint, &T)> {
+        self.data.iter().map(|(p, v)| (p.clone(), v))
+    }
+
+    fn adjacent(&self, p: &NDGridPoint, neighborhood: Neighborhood) -> Vec<NDGridPoint> {
+        match neighborhood {
+            Neighborhood::Cardinal => p.cardinal_neighbors(),
+            Neighborhood::All => p.neighbors(),
+        }
+    }
+}
+
+impl<T> GridMut for NDGrid<T> {
+    fn get_mut(&mut self, p: &NDGridPoint) -> Option<&mut T> {
+        self.data.get_mut(p)
+    }
+
+    fn set(&mut self, p: NDGridPoint, v: T) -> Option<T> {
+        self.data.insert(p, v)
+    }
+
+    fn remove(&mut self, p: &NDGridPoint) -> Option<T> {
+        self.data.remove(p)
+    }
+
+    fn indexed_retain(&mut self, f: impl Fn(NDGridPoint, Option<&T>) -> bool) {
+        NDGrid::indexed_retain(self, f);
+    }
+
+    fn indexed_update(&mut self, f: impl Fn(NDGridPoint, Option<&T>) -> Option<T>) {
+        NDGrid::indexed_update(self, f);
+    }
+}
+
+impl<T: Hash> Hash for NDGrid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        sparse_hash(&self.data, state);
+    }
+}
+
+impl<T: Ord> Ord for NDGrid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sorted_entries(&self.data).cmp(&sorted_entries(&other.data))
+    }
+}
+
+impl<T: Ord> PartialOrd for NDGrid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<T> Default for NDGrid<T> {
     fn default() -> Self {
         Self {
@@ -0,0 +1,142 @@
+use crate::{
+    grid::{dynamic_2d::Dynamic2DGrid, static_2d::Static2DGrid},
+    grid_point::{direction::Direction, signed::GridPoint},
+};
+use std::collections::{BTreeSet, HashMap};
+
+/// Removes `position` from the set of `line`, dropping the set once it is empty so that equal
+/// indexes compare equal
+fn unblock(lines: &mut HashMap<isize, BTreeSet<isize>>, line: isize, position: isize) {
+    if let Some(blocked) = lines.get_mut(&line) {
+        blocked.remove(&position);
+        if blocked.is_empty() {
+            lines.remove(&line);
+        }
+    }
+}
+
+/// Sorted blocked positions of every row and column, for finding the nearest obstacle in a
+/// direction in O(log n)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObstacleIndex {
+    /// Blocked columns of each row
+    rows: HashMap<isize, BTreeSet<isize>>,
+    /// Blocked rows of each column
+    columns: HashMap<isize, BTreeSet<isize>>,
+    /// Inclusive corners of the area movement is confined to, or `None` if it is unbounded
+    pub bounds: Option<(GridPoint, GridPoint)>,
+}
+
+impl ObstacleIndex {
+    pub fn new(bounds: Option<(GridPoint, GridPoint)>) -> Self {
+        Self {
+            rows: HashMap::new(),
+            columns: HashMap::new(),
+            bounds,
+        }
+    }
+
+    /// Index of the cells of `grid` matching `is_blocking`, bounded by the grid's edges
+    pub fn from_static<T>(grid: &Static2DGrid<T>, is_blocking: impl Fn(&T) -> bool) -> Self {
+        let bounds = (grid.rows() > 0 && grid.columns() > 0).then(|| {
+            (
+                GridPoint::new(0, 0),
+                GridPoint::new(grid.rows() as isize - 1, grid.columns() as isize - 1),
+            )
+        });
+        let mut index = Self::new(bounds);
+        for (r, c, v) in grid.indexed_iter() {
+            if v.as_ref().is_some_and(&is_blocking) {
+                index.set(GridPoint::new(r as isize, c as isize), true);
+            }
+        }
+        index
+    }
+
+    /// Index of the cells of `grid` matching `is_blocking`, confined to `bounds`. Sparse grids
+    /// often store only the obstacles, so pass the map's extent rather than
+    /// [`Dynamic2DGrid::bounds`] when it is larger.
+    pub fn from_dynamic<T>(
+        grid: &Dynamic2DGrid<T>,
+        bounds: Option<(GridPoint, GridPoint)>,
+        is_blocking: impl Fn(&T) -> bool,
+    ) -> Self {
+        let mut index = Self::new(bounds);
+        for (p, v) in grid.indexed_iter() {
+            if is_blocking(v) {
+                index.set(p, true);
+            }
+        }
+        index
+    }
+
+    pub fn is_blocked(&self, p: &GridPoint) -> bool {
+        self.rows.get(&p.r).is_some_and(|cs| cs.contains(&p.c))
+    }
+
+    /// Marks `p` as blocked or free. The bounds are left as they are, so obstacles outside them
+    /// never stop a slide.
+    pub fn set(&mut self, p: GridPoint, blocked: bool) {
+        if blocked {
+            self.rows.entry(p.r).or_default().insert(p.c);
+            self.columns.entry(p.c).or_default().insert(p.r);
+        } else {
+            unblock(&mut self.rows, p.r, p.c);
+            unblock(&mut self.columns, p.c, p.r);
+        }
+    }
+
+    /// Closest blocked cell strictly beyond `from` in `direction`
+    pub fn next_obstacle(&self, from: GridPoint, direction: Direction) -> Option<GridPoint> {
+        match direction {
+            Direction::North => self
+                .columns
+                .get(&from.c)?
+                .range(..from.r)
+                .next_back()
+                .map(|&r| GridPoint::new(r, from.c)),
+            Direction::South => self
+                .columns
+                .get(&from.c)?
+                .range(from.r + 1..)
+                .next()
+                .map(|&r| GridPoint::new(r, from.c)),
+            Direction::West => self
+                .rows
+                .get(&from.r)?
+                .range(..from.c)
+                .next_back()
+                .map(|&c| GridPoint::new(from.r, c)),
+            Direction::East => self
+                .rows
+                .get(&from.r)?
+                .range(from.c + 1..)
+                .next()
+                .map(|&c| GridPoint::new(from.r, c)),
+        }
+    }
+
+    /// Cell where movement from `from` in `direction` stops, just before the next obstacle or at
+    /// the edge of the bounds. `None` if nothing stops it.
+    pub fn slide(&self, from: GridPoint, direction: Direction) -> Option<GridPoint> {
+        let before_obstacle = self
+            .next_obstacle(from, direction)
+            .map(|p| p - direction.delta());
+        let edge = self.bounds.map(|(min, max)| match direction {
+            Direction::North => GridPoint::new(min.r, from.c),
+            Direction::South => GridPoint::new(max.r, from.c),
+            Direction::West => GridPoint::new(from.r, min.c),
+            Direction::East => GridPoint::new(from.r, max.c),
+        });
+        match (before_obstacle, edge) {
+            (Some(p), Some(e)) => Some(
+                if p.manhattan_distance(from) <= e.manhattan_distance(from) {
+                    p
+                } else {
+                    e
+                },
+            ),
+            (p, e) => p.or(e),
+        }
+    }
+}
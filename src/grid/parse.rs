@@ -0,0 +1,155 @@
+use crate::{
+    grid::{dynamic_2d::Dynamic2DGrid, static_2d::Static2DGrid},
+    grid_point::signed::GridPoint,
+};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result},
+};
+
+/// A character that could not be converted into a cell, with its zero-based position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseGridError {
+    pub r: usize,
+    pub c: usize,
+    pub ch: char,
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "invalid character {:?} at row {}, column {}",
+            self.ch, self.r, self.c
+        )
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// Every character of `input` with its row and column
+fn cells(input: &str) -> impl Iterator<Item = (usize, usize, char)> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(r, line)| line.chars().enumerate().map(move |(c, ch)| (r, c, ch)))
+}
+
+/// Number of lines and length of the longest line, in characters
+fn dimensions(input: &str) -> (usize, usize) {
+    let rows = input.lines().count();
+    let cols = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+    (rows, cols)
+}
+
+/// Converts a character with `TryFrom<char>`, leaving `blank` empty. `None` if it is invalid.
+fn convert<T: TryFrom<char>>(ch: char, blank: Option<char>) -> Option<Option<T>> {
+    if Some(ch) == blank {
+        return Some(None);
+    }
+    T::try_from(ch).ok().map(Some)
+}
+
+/// Value of a decimal digit, leaving `blank` empty. `None` if it is invalid.
+fn digit(ch: char, blank: Option<char>) -> Option<Option<u8>> {
+    if Some(ch) == blank {
+        return Some(None);
+    }
+    ch.to_digit(10).map(|d| Some(d as u8))
+}
+
+impl<T> Static2DGrid<T> {
+    /// Builds a grid with one row per line, leaving cells empty where `f` returns `None`
+    pub fn parse_with(input: &str, f: impl Fn(char) -> Option<T>) -> Self {
+        let (rows, cols) = dimensions(input);
+        let mut grid = Static2DGrid::new(rows, cols);
+        for (r, c, ch) in cells(input) {
+            if let Some(v) = f(ch) {
+                grid.set(r, c, v);
+            }
+        }
+        grid
+    }
+
+    /// Like [`Self::parse_with`], but `f` returns `None` for invalid characters and parsing fails
+    /// with the position of the first one
+    pub fn try_parse_with(
+        input: &str,
+        f: impl Fn(char) -> Option<Option<T>>,
+    ) -> std::result::Result<Self, ParseGridError> {
+        let (rows, cols) = dimensions(input);
+        let mut grid = Static2DGrid::new(rows, cols);
+        for (r, c, ch) in cells(input) {
+            if let Some(v) = f(ch).ok_or(ParseGridError { r, c, ch })? {
+                grid.set(r, c, v);
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Converts every character except `blank` with `TryFrom<char>`, failing on the first
+    /// character that does not convert. Note that `u8` converts to the character code, so
+    /// `'1'` becomes 49; use [`Static2DGrid::try_parse_digits`] for digit values.
+    pub fn try_parse(input: &str, blank: Option<char>) -> std::result::Result<Self, ParseGridError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::try_parse_with(input, |ch| convert(ch, blank))
+    }
+}
+
+impl Static2DGrid<u8> {
+    /// Converts every character except `blank` to the value of its decimal digit
+    pub fn try_parse_digits(
+        input: &str,
+        blank: Option<char>,
+    ) -> std::result::Result<Self, ParseGridError> {
+        Self::try_parse_with(input, |ch| digit(ch, blank))
+    }
+}
+
+impl<T> Dynamic2DGrid<T> {
+    /// Builds a grid with one row per line, leaving cells absent where `f` returns `None`
+    pub fn parse_with(input: &str, f: impl Fn(char) -> Option<T>) -> Self {
+        let data = cells(input)
+            .filter_map(|(r, c, ch)| Some((GridPoint::new(r as isize, c as isize), f(ch)?)))
+            .collect();
+        Dynamic2DGrid::new(data)
+    }
+
+    /// Like [`Self::parse_with`], but `f` returns `None` for invalid characters and parsing fails
+    /// with the position of the first one
+    pub fn try_parse_with(
+        input: &str,
+        f: impl Fn(char) -> Option<Option<T>>,
+    ) -> std::result::Result<Self, ParseGridError> {
+        let mut data = HashMap::new();
+        for (r, c, ch) in cells(input) {
+            if let Some(v) = f(ch).ok_or(ParseGridError { r, c, ch })? {
+                data.insert(GridPoint::new(r as isize, c as isize), v);
+            }
+        }
+        Ok(Dynamic2DGrid::new(data))
+    }
+
+    /// Converts every character except `blank` with `TryFrom<char>`, failing on the first
+    /// character that does not convert. Note that `u8` converts to the character code, so
+    /// `'1'` becomes 49; use [`Dynamic2DGrid::try_parse_digits`] for digit values.
+    pub fn try_parse(input: &str, blank: Option<char>) -> std::result::Result<Self, ParseGridError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::try_parse_with(input, |ch| convert(ch, blank))
+    }
+}
+
+impl Dynamic2DGrid<u8> {
+    /// Converts every character except `blank` to the value of its decimal digit
+    pub fn try_parse_digits(
+        input: &str,
+        blank: Option<char>,
+    ) -> std::result::Result<Self, ParseGridError> {
+        Self::try_parse_with(input, |ch| digit(ch, blank))
+    }
+}
@@ -0,0 +1,260 @@
+use crate::grid::{Grid, Neighborhood};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+pub struct Bfs<P> {
+    pub distances: HashMap<P, usize>,
+    pub parents: HashMap<P, P>,
+}
+
+impl<P: Clone + Eq + Hash> Bfs<P> {
+    pub fn distance(&self, p: &P) -> Option<usize> {
+        self.distances.get(p).copied()
+    }
+
+    pub fn reached(&self, p: &P) -> bool {
+        self.distances.contains_key(p)
+    }
+
+    /// Shortest path from the nearest start to `to`, both ends included
+    pub fn path(&self, to: &P) -> Option<Vec<P>> {
+        if !self.reached(to) {
+            return None;
+        }
+        let mut path = vec![to.clone()];
+        let mut current = to;
+        while let Some(parent) = self.parents.get(current) {
+            path.push(parent.clone());
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Visits every cell reachable from `starts` through cells whose value is `passable`.
+/// Start cells are always visited, absent cells never are.
+pub fn bfs<G: Grid>(
+    grid: &G,
+    starts: impl IntoIterator<Item = G::Point>,
+    neighborhood: Neighborhood,
+    passable: impl Fn(&G::Value) -> bool,
+) -> Bfs<G::Point> {
+    bfs_within(grid, starts, neighborhood, passable, usize::MAX)
+}
+
+/// Like [`bfs`], but stops exploring at `max_distance` steps, which makes it usable on
+/// unbounded grids such as [`TiledView`](crate::grid::wrapping::TiledView)
+pub fn bfs_within<G: Grid>(
+    grid: &G,
+    starts: impl IntoIterator<Item = G::Point>,
+    neighborhood: Neighborhood,
+    passable: impl Fn(&G::Value) -> bool,
+    max_distance: usize,
+) -> Bfs<G::Point> {
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(p) = queue.pop_front() {
+        let d = distances[&p];
+        if d == max_distance {
+            continue;
+        }
+        for n in grid.adjacent(&p, neighborhood) {
+            if distances.contains_key(&n) || !grid.get(&n).is_some_and(&passable) {
+                continue;
+            }
+            distances.insert(n.clone(), d + 1);
+            parents.insert(n.clone(), p.clone());
+            queue.push_back(n);
+        }
+    }
+
+    Bfs { distances, parents }
+}
+
+pub struct Dijkstra<P> {
+    pub costs: HashMap<P, u64>,
+    /// Every predecessor through which a cell is reached at its optimal cost
+    pub parents: HashMap<P, Vec<P>>,
+}
+
+impl<P: Clone + Eq + Hash> Dijkstra<P> {
+    pub fn cost(&self, p: &P) -> Option<u64> {
+        self.costs.get(p).copied()
+    }
+
+    /// One cheapest path from a start to `to`, both ends included
+    pub fn path(&self, to: &P) -> Option<Vec<P>> {
+        if !self.costs.contains_key(to) {
+            return None;
+        }
+        let mut path = vec![to.clone()];
+        let mut current = to;
+        while let Some(parent) = self.parents.get(current).and_then(|ps| ps.first()) {
+            path.push(parent.clone());
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cell lying on at least one cheapest path to `to`
+    pub fn optimal_cells(&self, to: &P) -> HashSet<P> {
+        let mut cells = HashSet::new();
+        if !self.costs.contains_key(to) {
+            return cells;
+        }
+        let mut stack = vec![to.clone()];
+        while let Some(p) = stack.pop() {
+            if !cells.insert(p.clone()) {
+                continue;
+            }
+            if let Some(ps) = self.parents.get(&p) {
+                stack.extend(ps.iter().cloned());
+            }
+        }
+        cells
+    }
+}
+
+/// Finds the cheapest cost from `starts` to every reachable cell. `cost` receives the cell being
+/// left, the cell being entered and the value of the entered cell, and returns `None` if the move
+/// is not allowed.
+pub fn dijkstra<G: Grid>(
+    grid: &G,
+    starts: impl IntoIterator<Item = G::Point>,
+    neighborhood: Neighborhood,
+    cost: impl Fn(&G::Point, &G::Point, &G::Value) -> Option<u64>,
+) -> Dijkstra<G::Point> {
+    let mut costs = HashMap::new();
+    let mut parents: HashMap<G::Point, Vec<G::Point>> = HashMap::new();
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if costs.insert(start.clone(), 0).is_none() {
+            heap.push(Reverse((0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((d, i))) = heap.pop() {
+        let p = nodes[i].clone();
+        if costs[&p] < d {
+            continue;
+        }
+        for n in grid.adjacent(&p, neighborhood) {
+            let Some(step) = grid.get(&n).and_then(|v| cost(&p, &n, v)) else {
+                continue;
+            };
+            let nd = d + step;
+            match costs.get(&n) {
+                Some(&best) if best < nd => {}
+                Some(&best) if best == nd => {
+                    // Starts have no parent list, so zero-cost moves back into them are ignored
+                    if let Some(ps) = parents.get_mut(&n) {
+                        ps.push(p.clone());
+                    }
+                }
+                _ => {
+                    costs.insert(n.clone(), nd);
+                    parents.insert(n.clone(), vec![p.clone()]);
+                    heap.push(Reverse((nd, nodes.len())));
+                    nodes.push(n);
+                }
+            }
+        }
+    }
+
+    Dijkstra { costs, parents }
+}
+
+/// Cheapest path from `start` to `goal` and its cost. `heuristic` must never overestimate the
+/// remaining cost, e.g. `|p| p.manhattan_distance(goal) as u64` for 4-way moves costing at least 1.
+/// Only one path is kept; use [`dijkstra`] and [`Dijkstra::optimal_cells`] to find every cell on
+/// any cheapest path.
+pub fn astar<G: Grid>(
+    grid: &G,
+    start: G::Point,
+    goal: &G::Point,
+    neighborhood: Neighborhood,
+    cost: impl Fn(&G::Point, &G::Point, &G::Value) -> Option<u64>,
+    heuristic: impl Fn(&G::Point) -> u64,
+) -> Option<(u64, Vec<G::Point>)> {
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut parents: HashMap<G::Point, G::Point> = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut nodes = vec![start];
+
+    while let Some(Reverse((_, d, i))) = heap.pop() {
+        let p = nodes[i].clone();
+        if costs[&p] < d {
+            continue;
+        }
+        if &p == goal {
+            let mut path = vec![p];
+            while let Some(parent) = parents.get(path.last()?) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((d, path));
+        }
+        for n in grid.adjacent(&p, neighborhood) {
+            let Some(step) = grid.get(&n).and_then(|v| cost(&p, &n, v)) else {
+                continue;
+            };
+            let nd = d + step;
+            if costs.get(&n).is_some_and(|&best| best <= nd) {
+                continue;
+            }
+            costs.insert(n.clone(), nd);
+            parents.insert(n.clone(), p.clone());
+            heap.push(Reverse((nd + heuristic(&n), nd, nodes.len())));
+            nodes.push(n);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::static_2d::Static2DGrid, grid_point::unsigned::UGridPoint};
+
+    fn digit_cost(_: &UGridPoint, _: &UGridPoint, v: &char) -> Option<u64> {
+        v.to_digit(10).map(u64::from)
+    }
+
+    #[test]
+    fn dijkstra_zero_cost_moves_into_starts() {
+        let grid = Static2DGrid::from("0012");
+        let p = UGridPoint::new;
+
+        let dj = dijkstra(&grid, [p(0, 0)], Neighborhood::Cardinal, digit_cost);
+        assert_eq!(dj.path(&p(0, 1)), Some(vec![p(0, 0), p(0, 1)]));
+        assert_eq!(dj.cost(&p(0, 3)), Some(3));
+        assert_eq!(dj.optimal_cells(&p(0, 3)).len(), 4);
+
+        let dj = dijkstra(
+            &grid,
+            [p(0, 0), p(0, 1)],
+            Neighborhood::Cardinal,
+            digit_cost,
+        );
+        assert_eq!(dj.path(&p(0, 0)), Some(vec![p(0, 0)]));
+        assert_eq!(dj.path(&p(0, 1)), Some(vec![p(0, 1)]));
+    }
+}
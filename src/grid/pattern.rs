@@ -0,0 +1,88 @@
+use crate::{
+    grid::{static_2d::Static2DGrid, transform::Dihedral, view::Static2DGridView},
+    grid_point::unsigned::UGridPoint,
+};
+use std::collections::HashSet;
+
+/// Pattern cells that must match: present and not equal to the wildcard
+fn required<'a, 'b, T: PartialEq>(
+    pattern: Static2DGridView<'a, T>,
+    wildcard: Option<&'b T>,
+) -> impl Iterator<Item = (usize, usize, &'a T)> + use<'a, 'b, T> {
+    pattern
+        .indexed_iter()
+        .filter_map(move |(r, c, v)| v.as_ref().map(|v| (r, c, v)))
+        .filter(move |&(_, _, v)| Some(v) != wildcard)
+}
+
+impl<T: PartialEq> Static2DGrid<T> {
+    fn find_view(&self, pattern: Static2DGridView<'_, T>, wildcard: Option<&T>) -> Vec<UGridPoint> {
+        let cells: Vec<_> = required(pattern, wildcard).collect();
+        let rs = (self.rows() + 1).saturating_sub(pattern.rows());
+        let cs = (self.columns() + 1).saturating_sub(pattern.columns());
+        (0..rs)
+            .flat_map(|r| (0..cs).map(move |c| UGridPoint::new(r, c)))
+            .filter(|p| {
+                cells
+                    .iter()
+                    .all(|&(r, c, v)| self.get(p.r + r, p.c + c) == Some(v))
+            })
+            .collect()
+    }
+
+    /// Top-left corners of every placement where each present cell of `pattern` equals the grid
+    /// cell under it. Absent pattern cells and cells equal to `wildcard` match anything.
+    pub fn find_pattern(&self, pattern: &Static2DGrid<T>, wildcard: Option<&T>) -> Vec<UGridPoint> {
+        self.find_view(pattern.view(), wildcard)
+    }
+
+    /// Like [`Self::find_pattern`], trying every rotation and reflection of `pattern`.
+    /// Orientations that look identical to an earlier one are skipped, so each placement is
+    /// reported once.
+    pub fn find_pattern_oriented(
+        &self,
+        pattern: &Static2DGrid<T>,
+        wildcard: Option<&T>,
+    ) -> Vec<(UGridPoint, Dihedral)> {
+        let mut seen: Vec<Static2DGridView<'_, T>> = Vec::new();
+        let mut matches = Vec::new();
+        for (t, view) in pattern.orientations() {
+            if seen.iter().any(|s| s.data == view.data) {
+                continue;
+            }
+            seen.push(view);
+            matches.extend(self.find_view(view, wildcard).into_iter().map(|p| (p, t)));
+        }
+        matches
+    }
+
+    /// Grid cells covered by the non-wildcard cells of `pattern` at each match
+    pub fn matched_cells(
+        pattern: &Static2DGrid<T>,
+        wildcard: Option<&T>,
+        matches: &[(UGridPoint, Dihedral)],
+    ) -> HashSet<UGridPoint> {
+        matches
+            .iter()
+            .flat_map(|&(p, t)| {
+                required(pattern.transformed_view(t), wildcard)
+                    .map(move |(r, c, _)| UGridPoint::new(p.r + r, p.c + c))
+            })
+            .collect()
+    }
+
+    /// Overwrites the cells covered by each match with `replacement`
+    pub fn replace_matches(
+        &mut self,
+        pattern: &Static2DGrid<T>,
+        wildcard: Option<&T>,
+        matches: &[(UGridPoint, Dihedral)],
+        replacement: T,
+    ) where
+        T: Clone,
+    {
+        for p in Self::matched_cells(pattern, wildcard, matches) {
+            self.set(p.r, p.c, replacement.clone());
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{grid::static_2d::Static2DGrid, grid_point::unsigned::UGridPoint};
+use ndarray::Array2;
+use std::ops::{Add, Range, Sub};
+
+/// Prefix sums over both axes of a numeric grid, answering rectangle sums in O(1).
+/// Absent cells count as `T::default()`.
+#[derive(Clone)]
+pub struct SummedAreaTable<T> {
+    /// `sums[(r, c)]` is the sum of all cells above and to the left of `(r, c)`, exclusive
+    pub sums: Array2<T>,
+}
+
+impl<T> SummedAreaTable<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Default + Copy,
+{
+    pub fn new(grid: &Static2DGrid<T>) -> Self {
+        Self {
+            sums: prefix_sums(grid.rows(), grid.columns(), |r, c| {
+                grid.get(r, c).copied().unwrap_or_default()
+            }),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.sums.shape()[0] - 1
+    }
+
+    pub fn columns(&self) -> usize {
+        self.sums.shape()[1] - 1
+    }
+
+    /// Sum of the cells in the half-open rectangle `rows` x `columns`
+    pub fn sum(&self, rows: Range<usize>, columns: Range<usize>) -> T {
+        let s = &self.sums;
+        // Added before subtracting so unsigned sums never underflow
+        (s[(rows.end, columns.end)] + s[(rows.start, columns.start)])
+            - s[(rows.start, columns.end)]
+            - s[(rows.end, columns.start)]
+    }
+
+    pub fn total(&self) -> T {
+        self.sums[(self.rows(), self.columns())]
+    }
+
+    /// Top-left corner and sum of the k x k square with the largest sum, preferring the first in
+    /// row-major order on ties
+    pub fn best_window(&self, k: usize) -> Option<(UGridPoint, T)>
+    where
+        T: PartialOrd,
+    {
+        if k == 0 || k > self.rows() || k > self.columns() {
+            return None;
+        }
+        let mut best: Option<(UGridPoint, T)> = None;
+        for r in 0..=self.rows() - k {
+            for c in 0..=self.columns() - k {
+                let sum = self.sum(r..r + k, c..c + k);
+                if best.is_none_or(|(_, b)| sum > b) {
+                    best = Some((UGridPoint::new(r, c), sum));
+                }
+            }
+        }
+        best
+    }
+}
+
+fn prefix_sums<T>(rows: usize, columns: usize, cell: impl Fn(usize, usize) -> T) -> Array2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Default + Copy,
+{
+    let mut sums = Array2::default((rows + 1, columns + 1));
+    for r in 0..rows {
+        for c in 0..columns {
+            sums[(r + 1, c + 1)] =
+                (cell(r, c) + sums[(r, c + 1)] + sums[(r + 1, c)]) - sums[(r, c)];
+        }
+    }
+    sums
+}
+
+/// Accumulates additions over rectangles in O(1) each, materialised into a grid in one pass.
+/// Increments and decrements are tracked separately so that unsigned types work too.
+#[derive(Clone)]
+pub struct DifferenceArray2D<T> {
+    increments: Array2<T>,
+    decrements: Array2<T>,
+}
+
+impl<T> DifferenceArray2D<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Default + Copy,
+{
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            increments: Array2::default((rows + 1, columns + 1)),
+            decrements: Array2::default((rows + 1, columns + 1)),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.increments.shape()[0] - 1
+    }
+
+    pub fn columns(&self) -> usize {
+        self.increments.shape()[1] - 1
+    }
+
+    /// Adds `v` to every cell in the half-open rectangle `rows` x `columns`
+    pub fn add(&mut self, rows: Range<usize>, columns: Range<usize>, v: T) {
+        let (r0, r1, c0, c1) = (rows.start, rows.end, columns.start, columns.end);
+        self.increments[(r0, c0)] = self.increments[(r0, c0)] + v;
+        self.increments[(r1, c1)] = self.increments[(r1, c1)] + v;
+        self.decrements[(r0, c1)] = self.decrements[(r0, c1)] + v;
+        self.decrements[(r1, c0)] = self.decrements[(r1, c0)] + v;
+    }
+
+    pub fn build(&self) -> Static2DGrid<T> {
+        let (rows, columns) = (self.rows(), self.columns());
+        let increments = prefix_sums(rows, columns, |r, c| self.increments[(r, c)]);
+        let decrements = prefix_sums(rows, columns, |r, c| self.decrements[(r, c)]);
+        let mut grid = Static2DGrid::new(rows, columns);
+        for r in 0..rows {
+            for c in 0..columns {
+                grid.set(
+                    r,
+                    c,
+                    increments[(r + 1, c + 1)] - decrements[(r + 1, c + 1)],
+                );
+            }
+        }
+        grid
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    grid::{dynamic_2d::Dynamic2DGrid, static_2d::Static2DGrid},
+    grid_point::{signed::GridPoint, unsigned::UGridPoint},
+};
+use std::iter;
+
+/// Points after `from` in steps of `direction`, without end
+fn steps(from: GridPoint, direction: GridPoint) -> impl Iterator<Item = GridPoint> {
+    assert!(
+        direction != GridPoint::new(0, 0),
+        "ray direction must be non-zero"
+    );
+    iter::successors(Some(from + direction), move |&p| Some(p + direction))
+}
+
+fn directions() -> [GridPoint; 8] {
+    GridPoint::new(0, 0).all_neighbors()
+}
+
+impl<T> Static2DGrid<T> {
+    fn to_unsigned(&self, p: GridPoint) -> Option<UGridPoint> {
+        let p = UGridPoint::new(p.r.try_into().ok()?, p.c.try_into().ok()?);
+        self.in_bounds(p.r, p.c).then_some(p)
+    }
+
+    /// Present cells met when walking from `from` (exclusive) in steps of `direction`, until the
+    /// walk leaves the grid
+    pub fn ray(
+        &self,
+        from: UGridPoint,
+        direction: GridPoint,
+    ) -> impl Iterator<Item = (UGridPoint, &T)> {
+        steps(from.into(), direction)
+            .map_while(|p| self.to_unsigned(p))
+            .filter_map(|p| Some((p, self.get(p.r, p.c)?)))
+    }
+
+    /// First cell along the ray that satisfies `predicate`
+    pub fn first_visible(
+        &self,
+        from: UGridPoint,
+        direction: GridPoint,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Option<(UGridPoint, &T)> {
+        self.ray(from, direction).find(|(_, v)| predicate(v))
+    }
+
+    /// Start and direction of every occurrence of `sequence` written along a straight line in
+    /// any of the eight directions
+    pub fn find_sequence(&self, sequence: &[T]) -> Vec<(UGridPoint, GridPoint)>
+    where
+        T: PartialEq,
+    {
+        let Some((first, rest)) = sequence.split_first() else {
+            return Vec::new();
+        };
+        let mut found = Vec::new();
+        for (r, c, v) in self.indexed_iter() {
+            if v.as_ref() != Some(first) {
+                continue;
+            }
+            let start = UGridPoint::new(r, c);
+            for d in directions() {
+                let matches = steps(start.into(), d).zip(rest).all(|(p, expected)| {
+                    self.to_unsigned(p)
+                        .and_then(|p| self.get(p.r, p.c))
+                        .is_some_and(|v| v == expected)
+                });
+                if matches {
+                    found.push((start, d));
+                }
+            }
+        }
+        found
+    }
+}
+
+impl Static2DGrid<char> {
+    pub fn find_word(&self, word: &str) -> Vec<(UGridPoint, GridPoint)> {
+        self.find_sequence(&word.chars().collect::<Vec<_>>())
+    }
+}
+
+impl<T> Dynamic2DGrid<T> {
+    /// Present cells met when walking from `from` (exclusive) in steps of `direction`, until the
+    /// walk leaves [`Self::bounds`]. The bounds are recomputed on every call; use
+    /// [`Self::ray_within`] for repeated queries.
+    pub fn ray(
+        &self,
+        from: GridPoint,
+        direction: GridPoint,
+    ) -> impl Iterator<Item = (GridPoint, &T)> {
+        // An empty grid has no bounds, and a ray confined to `from` yields nothing
+        let bounds = self.bounds().unwrap_or((from, from));
+        self.ray_within(from, direction, bounds)
+    }
+
+    /// Like [`Self::ray`], but walking until the ray leaves the inclusive corners `bounds`
+    pub fn ray_within(
+        &self,
+        from: GridPoint,
+        direction: GridPoint,
+        (min, max): (GridPoint, GridPoint),
+    ) -> impl Iterator<Item = (GridPoint, &T)> {
+        steps(from, direction)
+            .take_while(move |p| (min.r..=max.r).contains(&p.r) && (min.c..=max.c).contains(&p.c))
+            .filter_map(|p| Some((p, self.get(&p)?)))
+    }
+
+    /// First cell along the ray that satisfies `predicate`. The bounds are recomputed on every
+    /// call; use [`Self::first_visible_within`] for repeated queries.
+    pub fn first_visible(
+        &self,
+        from: GridPoint,
+        direction: GridPoint,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Option<(GridPoint, &T)> {
+        self.ray(from, direction).find(|(_, v)| predicate(v))
+    }
+
+    /// First cell along [`Self::ray_within`] that satisfies `predicate`
+    pub fn first_visible_within(
+        &self,
+        from: GridPoint,
+        direction: GridPoint,
+        bounds: (GridPoint, GridPoint),
+        predicate: impl Fn(&T) -> bool,
+    ) -> Option<(GridPoint, &T)> {
+        self.ray_within(from, direction, bounds)
+            .find(|(_, v)| predicate(v))
+    }
+
+    /// Start and direction of every occurrence of `sequence` written along a straight line in
+    /// any of the eight directions
+    pub fn find_sequence(&self, sequence: &[T]) -> Vec<(GridPoint, GridPoint)>
+    where
+        T: PartialEq,
+    {
+        let Some((first, rest)) = sequence.split_first() else {
+            return Vec::new();
+        };
+        let mut found = Vec::new();
+        for (start, v) in self.indexed_iter() {
+            if v != first {
+                continue;
+            }
+            for d in directions() {
+                let matches = steps(start, d)
+                    .zip(rest)
+                    .all(|(p, expected)| self.get(&p) == Some(expected));
+                if matches {
+                    found.push((start, d));
+                }
+            }
+        }
+        found
+    }
+}
+
+impl Dynamic2DGrid<char> {
+    pub fn find_word(&self, word: &str) -> Vec<(GridPoint, GridPoint)> {
+        self.find_sequence(&word.chars().collect::<Vec<_>>())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::grid::static_2d::Static2DGrid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// A horizontal line between two rows
+    Horizontal,
+    /// A vertical line between two columns
+    Vertical,
+}
+
+/// A mirror line and the number of cells that differ from their reflection
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Reflection {
+    pub axis: Axis,
+    /// Number of rows above or columns left of the line
+    pub index: usize,
+    /// Number of mismatched pairs of cells
+    pub mismatches: u32,
+}
+
+/// Bitmask of each line, 64 cells per word
+fn masks<'a, T: 'a>(
+    lines: impl Iterator<Item = impl Iterator<Item = Option<&'a T>>>,
+    is_set: impl Fn(Option<&T>) -> bool,
+) -> Vec<Vec<u64>> {
+    lines
+        .map(|line| {
+            let mut words = Vec::new();
+            for (i, cell) in line.enumerate() {
+                if i % 64 == 0 {
+                    words.push(0);
+                }
+                if is_set(cell) {
+                    words[i / 64] |= 1 << (i % 64);
+                }
+            }
+            words
+        })
+        .collect()
+}
+
+/// Positions between lines where the lines mirror each other up to the shorter side, with at
+/// most `max_mismatches` differing bits
+fn mirror_lines(masks: &[Vec<u64>], max_mismatches: u32) -> Vec<(usize, u32)> {
+    (1..masks.len())
+        .filter_map(|index| {
+            let mut mismatches = 0;
+            for (a, b) in masks[..index].iter().rev().zip(&masks[index..]) {
+                mismatches += a
+                    .iter()
+                    .zip(b)
+                    .map(|(x, y)| (x ^ y).count_ones())
+                    .sum::<u32>();
+                if mismatches > max_mismatches {
+                    return None;
+                }
+            }
+            Some((index, mismatches))
+        })
+        .collect()
+}
+
+impl<T> Static2DGrid<T> {
+    /// Every horizontal and vertical mirror line of the grid with at most `max_mismatches`
+    /// mismatched cells, where cells are compared by `is_set`. Lines are only compared as far as
+    /// the nearer edge.
+    pub fn reflections(
+        &self,
+        max_mismatches: u32,
+        is_set: impl Fn(Option<&T>) -> bool,
+    ) -> Vec<Reflection> {
+        let found = |axis, masks: Vec<Vec<u64>>| {
+            mirror_lines(&masks, max_mismatches)
+                .into_iter()
+                .map(move |(index, mismatches)| Reflection {
+                    axis,
+                    index,
+                    mismatches,
+                })
+        };
+        found(Axis::Horizontal, masks(self.rows_iter(), &is_set))
+            .chain(found(Axis::Vertical, masks(self.columns_iter(), &is_set)))
+            .collect()
+    }
+}
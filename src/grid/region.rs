@@ -0,0 +1,166 @@
+use crate::{
+    grid::{Grid, Neighborhood, dynamic_2d::Dynamic2DGrid, static_2d::Static2DGrid},
+    grid_point::{signed::GridPoint, unsigned::UGridPoint},
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Cells reachable from `start` by repeatedly stepping to a neighbor for which
+/// `same(current, neighbor)` holds, in breadth-first order
+pub fn flood_fill<G: Grid>(
+    grid: &G,
+    start: G::Point,
+    neighborhood: Neighborhood,
+    same: impl Fn(&G::Value, &G::Value) -> bool,
+) -> Vec<G::Point> {
+    if !grid.contains(&start) {
+        return Vec::new();
+    }
+    let mut seen = HashSet::from([start.clone()]);
+    let mut region = Vec::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(p) = queue.pop_front() {
+        let v = grid.get(&p).unwrap();
+        for n in grid.indexed_neighbors(&p, neighborhood) {
+            if !seen.contains(&n) && same(v, grid.get(&n).unwrap()) {
+                seen.insert(n.clone());
+                queue.push_back(n);
+            }
+        }
+        region.push(p);
+    }
+
+    region
+}
+
+pub struct Components<P> {
+    pub labels: HashMap<P, usize>,
+    /// Cells of each region, indexed by label
+    pub regions: Vec<Vec<P>>,
+}
+
+impl<P: Eq + Hash> Components<P> {
+    pub fn label(&self, p: &P) -> Option<usize> {
+        self.labels.get(p).copied()
+    }
+
+    pub fn region_of(&self, p: &P) -> Option<&[P]> {
+        self.label(p).map(|l| self.regions[l].as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+impl<P: Clone + Into<GridPoint>> Components<P> {
+    pub fn region(&self, label: usize) -> Region {
+        self.regions[label].iter().cloned().collect()
+    }
+}
+
+impl Components<UGridPoint> {
+    pub fn label_grid(&self, rows: usize, columns: usize) -> Static2DGrid<usize> {
+        let mut grid = Static2DGrid::new(rows, columns);
+        for (p, &label) in &self.labels {
+            grid.set(p.r, p.c, label);
+        }
+        grid
+    }
+}
+
+impl Components<GridPoint> {
+    pub fn label_grid(&self) -> Dynamic2DGrid<usize> {
+        Dynamic2DGrid::new(self.labels.clone())
+    }
+}
+
+/// Splits every cell of the grid into connected regions, see [`flood_fill`]
+pub fn components<G: Grid>(
+    grid: &G,
+    neighborhood: Neighborhood,
+    same: impl Fn(&G::Value, &G::Value) -> bool,
+) -> Components<G::Point> {
+    let mut labels = HashMap::new();
+    let mut regions = Vec::new();
+
+    for (p, _) in grid.indexed_iter() {
+        if labels.contains_key(&p) {
+            continue;
+        }
+        let region = flood_fill(grid, p, neighborhood, &same);
+        for q in &region {
+            labels.insert(q.clone(), regions.len());
+        }
+        regions.push(region);
+    }
+
+    Components { labels, regions }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Region {
+    pub cells: HashSet<GridPoint>,
+}
+
+impl Region {
+    pub fn new(cells: HashSet<GridPoint>) -> Self {
+        Self { cells }
+    }
+
+    pub fn contains(&self, p: &GridPoint) -> bool {
+        self.cells.contains(p)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges facing a cell outside the region, including edges around holes
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|p| {
+                p.cardinal_neighbors()
+                    .iter()
+                    .filter(|n| !self.contains(n))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight sides, which equals the number of corners. Cells touching only
+    /// diagonally count as separate outlines meeting at a point.
+    pub fn sides(&self) -> usize {
+        let diagonals = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+        self.cells
+            .iter()
+            .map(|p| {
+                diagonals
+                    .iter()
+                    .filter(|&&(dr, dc)| {
+                        let vertical = self.contains(&GridPoint::new(p.r + dr, p.c));
+                        let horizontal = self.contains(&GridPoint::new(p.r, p.c + dc));
+                        let diagonal = self.contains(&GridPoint::new(p.r + dr, p.c + dc));
+                        (!vertical && !horizontal) || (vertical && horizontal && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+impl<P: Into<GridPoint>> FromIterator<P> for Region {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{grid::static_2d::Static2DGrid, grid_point::unsigned::UGridPoint};
+use ndarray::{Array2, s};
+
+impl<T> Static2DGrid<T> {
+    /// Moves every cell to the position `place` gives it in a `rows` x `cs` grid, returning
+    /// the cells it maps to `None` in row-major order
+    fn rebuild(
+        &mut self,
+        rows: usize,
+        cs: usize,
+        place: impl Fn(usize, usize) -> Option<(usize, usize)>,
+    ) -> Vec<Option<T>> {
+        let old = std::mem::replace(&mut self.data, Array2::default((rows, cs)));
+        let mut dropped = Vec::new();
+        for (r, c, v) in (Static2DGrid { data: old }).into_indexed_iter() {
+            match place(r, c) {
+                Some(p) => self.data[p] = v,
+                None => dropped.push(v),
+            }
+        }
+        dropped
+    }
+
+    /// Surrounds the grid with a border `n` cells wide of `fill`
+    pub fn pad(self, n: usize, fill: Option<T>) -> Self
+    where
+        T: Clone,
+    {
+        let (rows, cs) = (self.rows() + 2 * n, self.columns() + 2 * n);
+        let mut data = Array2::from_elem((rows, cs), fill);
+        for (r, c, v) in self.into_indexed_iter() {
+            data[(r + n, c + n)] = v;
+        }
+        Self { data }
+    }
+
+    /// Smallest rectangle containing every present cell, as inclusive top-left and bottom-right
+    /// corners
+    pub fn content_bounds(&self) -> Option<(UGridPoint, UGridPoint)> {
+        let mut present = self
+            .indexed_iter()
+            .filter(|(_, _, v)| v.is_some())
+            .map(|(r, c, _)| (r, c));
+        let (r, c) = present.next()?;
+        let (min, max) = present.fold(
+            (UGridPoint::new(r, c), UGridPoint::new(r, c)),
+            |(min, max), (r, c)| {
+                (
+                    UGridPoint::new(min.r.min(r), min.c.min(c)),
+                    UGridPoint::new(max.r.max(r), max.c.max(c)),
+                )
+            },
+        );
+        Some((min, max))
+    }
+
+    /// Shrinks the grid to [`Self::content_bounds`], or to 0 x 0 if it is blank
+    pub fn crop_to_content(self) -> Self {
+        match self.content_bounds() {
+            Some((min, max)) => Self {
+                data: self.data.slice_move(s![min.r..=max.r, min.c..=max.c]),
+            },
+            None => Self::new(0, 0),
+        }
+    }
+
+    /// Changes the size in place, keeping cells at the same position. Cells outside the new
+    /// size are dropped and new cells are empty.
+    pub fn resize(&mut self, rows: usize, cs: usize) {
+        self.rebuild(rows, cs, |r, c| (r < rows && c < cs).then_some((r, c)));
+    }
+
+    /// Inserts an empty row before row `r`
+    pub fn insert_row(&mut self, r: usize) {
+        assert!(r <= self.rows(), "row index out of bounds");
+        self.rebuild(self.rows() + 1, self.columns(), |i, c| {
+            Some((if i < r { i } else { i + 1 }, c))
+        });
+    }
+
+    /// Inserts an empty column before column `c`
+    pub fn insert_column(&mut self, c: usize) {
+        assert!(c <= self.columns(), "column index out of bounds");
+        self.rebuild(self.rows(), self.columns() + 1, |r, i| {
+            Some((r, if i < c { i } else { i + 1 }))
+        });
+    }
+
+    /// Removes row `r`, returning its cells
+    pub fn remove_row(&mut self, r: usize) -> Vec<Option<T>> {
+        assert!(r < self.rows(), "row index out of bounds");
+        self.rebuild(self.rows() - 1, self.columns(), |i, c| match i {
+            i if i < r => Some((i, c)),
+            i if i > r => Some((i - 1, c)),
+            _ => None,
+        })
+    }
+
+    /// Removes column `c`, returning its cells
+    pub fn remove_column(&mut self, c: usize) -> Vec<Option<T>> {
+        assert!(c < self.columns(), "column index out of bounds");
+        self.rebuild(self.rows(), self.columns() - 1, |r, i| match i {
+            i if i < c => Some((r, i)),
+            i if i > c => Some((r, i - 1)),
+            _ => None,
+        })
+    }
+
+    /// Start of every row and every column after expansion, followed by the expanded row and
+    /// column counts
+    fn expansion_offsets(
+        &self,
+        factor: usize,
+        is_empty: impl Fn(Option<&T>) -> bool,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let stretch = |empty: Vec<bool>| {
+            let mut offsets = vec![0];
+            for e in empty {
+                offsets.push(offsets[offsets.len() - 1] + if e { factor } else { 1 });
+            }
+            offsets
+        };
+        let rows = (0..self.rows())
+            .map(|r| (0..self.columns()).all(|c| is_empty(self.get(r, c))))
+            .collect();
+        let cs = (0..self.columns())
+            .map(|c| (0..self.rows()).all(|r| is_empty(self.get(r, c))))
+            .collect();
+        (stretch(rows), stretch(cs))
+    }
+
+    /// New index of every row and every column after each row or column whose cells are all
+    /// `is_empty` has been repeated `factor` times. Useful when the expanded grid would be too
+    /// large to build.
+    pub fn expansion_map(
+        &self,
+        factor: usize,
+        is_empty: impl Fn(Option<&T>) -> bool,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let (mut rows, mut cs) = self.expansion_offsets(factor, is_empty);
+        rows.pop();
+        cs.pop();
+        (rows, cs)
+    }
+
+    /// Repeats every row and every column whose cells are all `is_empty` `factor` times
+    pub fn expand_empty(&self, factor: usize, is_empty: impl Fn(Option<&T>) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let (rows, cs) = self.expansion_offsets(factor, is_empty);
+        let mut grid = Static2DGrid::new(rows[rows.len() - 1], cs[cs.len() - 1]);
+        for (r, c, v) in self.indexed_iter() {
+            let Some(v) = v else { continue };
+            for nr in rows[r]..rows[r + 1] {
+                for nc in cs[c]..cs[c + 1] {
+                    grid.set(nr, nc, v.clone());
+                }
+            }
+        }
+        grid
+    }
+}
@@ -0,0 +1,85 @@
+use crate::grid::{static_2d::Static2DGrid, view::Static2DGridView};
+use ndarray::Array2;
+
+impl<T> Static2DGrid<T> {
+    /// Replaces every cell with the block `expand` returns for it. Blocks in the same row must
+    /// have the same height and blocks in the same column the same width.
+    pub fn upscale<U>(&self, expand: impl Fn(Option<&T>) -> Static2DGrid<U>) -> Static2DGrid<U> {
+        Static2DGrid::join(Static2DGrid {
+            data: self.data.map(|cell| Some(expand(cell.as_ref()))),
+        })
+    }
+
+    /// Replaces every cell with a `k` x `k` block of copies
+    pub fn scale(&self, k: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.upscale(|cell| Static2DGrid {
+            data: Array2::from_elem((k, k), cell.cloned()),
+        })
+    }
+
+    /// Grid of `f` applied to every non-overlapping `k` x `k` block. Panics if the size of the
+    /// grid isn't a multiple of `k`.
+    pub fn downscale<U>(
+        &self,
+        k: usize,
+        f: impl Fn(Static2DGridView<'_, T>) -> Option<U>,
+    ) -> Static2DGrid<U> {
+        assert!(
+            self.rows().is_multiple_of(k) && self.columns().is_multiple_of(k),
+            "grid size is not a multiple of the block size"
+        );
+        let mut result = Static2DGrid::new(self.rows() / k, self.columns() / k);
+        for r in 0..result.rows() {
+            for c in 0..result.columns() {
+                result.data[(r, c)] = f(self.sub_grid(r * k..(r + 1) * k, c * k..(c + 1) * k));
+            }
+        }
+        result
+    }
+
+    /// Splits the grid into `k` x `k` blocks. Panics if the size of the grid isn't a multiple of
+    /// `k`.
+    pub fn blocks(&self, k: usize) -> Static2DGrid<Static2DGrid<T>>
+    where
+        T: Clone,
+    {
+        self.downscale(k, |block| Some(block.to_grid()))
+    }
+
+    /// Places the blocks next to each other, the inverse of [`Self::blocks`]. Blocks in the same
+    /// row must have the same height and blocks in the same column the same width. Missing
+    /// blocks are left empty, taking their size from the other blocks in their row and column.
+    pub fn join(blocks: Static2DGrid<Static2DGrid<T>>) -> Self {
+        let mut heights = vec![None; blocks.rows()];
+        let mut widths = vec![None; blocks.columns()];
+        for (r, c, block) in blocks.indexed_iter() {
+            let Some(block) = block else { continue };
+            let height = *heights[r].get_or_insert(block.rows());
+            let width = *widths[c].get_or_insert(block.columns());
+            assert!(
+                height == block.rows() && width == block.columns(),
+                "blocks do not line up"
+            );
+        }
+        let offsets = |sizes: Vec<Option<usize>>| {
+            let mut offsets = vec![0];
+            for size in sizes {
+                offsets.push(offsets[offsets.len() - 1] + size.unwrap_or(0));
+            }
+            offsets
+        };
+        let (rs, cs) = (offsets(heights), offsets(widths));
+
+        let mut result = Self::new(rs[rs.len() - 1], cs[cs.len() - 1]);
+        for (br, bc, block) in blocks.into_indexed_iter() {
+            let Some(block) = block else { continue };
+            for (r, c, v) in block.into_indexed_iter() {
+                result.data[(rs[br] + r, cs[bc] + c)] = v;
+            }
+        }
+        result
+    }
+}
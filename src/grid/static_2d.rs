@@ -1,11 +1,18 @@
-use crate::grid_point::unsigned::UGridPoint;
+use crate::{
+    grid::{Grid, GridMut, Neighborhood},
+    grid_point::unsigned::UGridPoint,
+};
 
 use ndarray::{
     Array2, Dim,
     iter::{IntoIter, Iter, IterMut},
 };
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result},
+};
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Static2DGrid<T> {
     pub data: Array2<Option<T>>,
 }
@@ -195,6 +202,76 @@ impl<T> Static2DGrid<T> {
     }
 }
 
+impl<T> Grid for Static2DGrid<T> {
+    type Point = UGridPoint;
+    type Value = T;
+
+    fn get(&self, p: &UGridPoint) -> Option<&T> {
+        Static2DGrid::get(self, p.r, p.c)
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (UGridPoint, &T)> {
+        self.data
+            .indexed_iter()
+            .filter_map(|((r, c), v)| Some((UGridPoint::new(r, c), v.as_ref()?)))
+    }
+
+    fn adjacent(&self, p: &UGridPoint, neighborhood: Neighborhood) -> Vec<UGridPoint> {
+        let candidates = match neighborhood {
+            Neighborhood::Cardinal => p.cardinal_neighbors().to_vec(),
+            Neighborhood::All => p.all_neighbors().to_vec(),
+        };
+        candidates
+            .into_iter()
+            .filter(|n| self.in_bounds(n.r, n.c))
+            .collect()
+    }
+}
+
+impl<T> GridMut for Static2DGrid<T> {
+    fn get_mut(&mut self, p: &UGridPoint) -> Option<&mut T> {
+        Static2DGrid::get_mut(self, p.r, p.c)
+    }
+
+    fn set(&mut self, p: UGridPoint, v: T) -> Option<T> {
+        Static2DGrid::set(self, p.r, p.c, v)
+    }
+
+    fn remove(&mut self, p: &UGridPoint) -> Option<T> {
+        self.data.get_mut((p.r, p.c))?.take()
+    }
+
+    fn indexed_retain(&mut self, f: impl Fn(UGridPoint, Option<&T>) -> bool) {
+        Static2DGrid::indexed_retain(self, |r, c, v| v.is_none() || f(UGridPoint::new(r, c), v));
+    }
+
+    fn indexed_update(&mut self, f: impl Fn(UGridPoint, Option<&T>) -> Option<T>) {
+        for ((r, c), cell) in self.data.indexed_iter_mut() {
+            if let Some(v) = cell.as_ref()
+                && let Some(new) = f(UGridPoint::new(r, c), Some(v))
+            {
+                *cell = Some(new);
+            }
+        }
+    }
+}
+
+/// Compares the shapes first, then the cells in row-major order
+impl<T: Ord> Ord for Static2DGrid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data
+            .shape()
+            .cmp(other.data.shape())
+            .then_with(|| self.data.iter().cmp(other.data.iter()))
+    }
+}
+
+impl<T: Ord> PartialOrd for Static2DGrid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Static2DGrid<T> {
     type Item = &'a Option<T>;
     type IntoIter = Iter<'a, Option<T>, Dim<[usize; 2]>>;
@@ -225,20 +302,7 @@ impl<T> IntoIterator for Static2DGrid<T> {
 
 impl From<&str> for Static2DGrid<char> {
     fn from(s: &str) -> Self {
-        let lines: Vec<&str> = s.lines().collect();
-        let rows = lines.len();
-        let cols = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
-        let mut grid = Static2DGrid::new(rows, cols);
-
-        for (r, line) in lines.iter().enumerate() {
-            for (c, ch) in line.chars().enumerate() {
-                if ch != ' ' {
-                    grid.set(r, c, ch);
-                }
-            }
-        }
-
-        grid
+        Static2DGrid::parse_with(s, |ch| (ch != ' ').then_some(ch))
     }
 }
 
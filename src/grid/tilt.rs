@@ -0,0 +1,145 @@
+use crate::{
+    grid::{dynamic_2d::Dynamic2DGrid, static_2d::Static2DGrid},
+    grid_point::{direction::Direction, signed::GridPoint},
+};
+
+enum Cell {
+    Movable,
+    Blocking,
+    Empty,
+}
+
+/// Packs the movable cells of `line` towards its start. `line` lists positions beginning at the
+/// edge the cells slide towards, and `swap` exchanges the contents of two positions.
+fn tilt_line<G, P: Copy>(
+    grid: &mut G,
+    line: &[P],
+    cell: impl Fn(&G, P) -> Cell,
+    swap: impl Fn(&mut G, P, P),
+) {
+    let mut free = 0;
+    for (i, &p) in line.iter().enumerate() {
+        match cell(grid, p) {
+            Cell::Blocking => free = i + 1,
+            Cell::Movable => {
+                if free != i {
+                    swap(grid, p, line[free]);
+                }
+                free += 1;
+            }
+            Cell::Empty => {}
+        }
+    }
+}
+
+fn classify<T>(
+    v: Option<&T>,
+    is_movable: &impl Fn(&T) -> bool,
+    is_blocking: &impl Fn(&T) -> bool,
+) -> Cell {
+    match v {
+        Some(v) if is_blocking(v) => Cell::Blocking,
+        Some(v) if is_movable(v) => Cell::Movable,
+        _ => Cell::Empty,
+    }
+}
+
+impl<T> Static2DGrid<T> {
+    /// Slides every movable cell towards `direction` until it hits the edge, a blocking cell or
+    /// another movable cell. Whatever it slides over is moved back into its old place.
+    pub fn tilt(
+        &mut self,
+        direction: Direction,
+        is_movable: impl Fn(&T) -> bool,
+        is_blocking: impl Fn(&T) -> bool,
+    ) {
+        let (rows, cs) = (self.rows(), self.columns());
+        let lines = if direction.is_vertical() { cs } else { rows };
+        for i in 0..lines {
+            let line: Vec<(usize, usize)> = match direction {
+                Direction::North => (0..rows).map(|r| (r, i)).collect(),
+                Direction::South => (0..rows).rev().map(|r| (r, i)).collect(),
+                Direction::West => (0..cs).map(|c| (i, c)).collect(),
+                Direction::East => (0..cs).rev().map(|c| (i, c)).collect(),
+            };
+            tilt_line(
+                self,
+                &line,
+                |g, (r, c)| classify(g.get(r, c), &is_movable, &is_blocking),
+                |g, a, b| g.data.swap(a, b),
+            );
+        }
+    }
+
+    /// Applies one tilt per direction, in order
+    pub fn spin_cycle(
+        &mut self,
+        directions: &[Direction],
+        is_movable: impl Fn(&T) -> bool,
+        is_blocking: impl Fn(&T) -> bool,
+    ) {
+        for &d in directions {
+            self.tilt(d, &is_movable, &is_blocking);
+        }
+    }
+}
+
+impl<T> Dynamic2DGrid<T> {
+    /// Slides every movable cell towards `direction` until it hits the edge of [`Self::bounds`],
+    /// a blocking cell or another movable cell. Absent cells count as empty space.
+    pub fn tilt(
+        &mut self,
+        direction: Direction,
+        is_movable: impl Fn(&T) -> bool,
+        is_blocking: impl Fn(&T) -> bool,
+    ) {
+        let Some((min, max)) = self.bounds() else {
+            return;
+        };
+        let lines = if direction.is_vertical() {
+            min.c..=max.c
+        } else {
+            min.r..=max.r
+        };
+        for i in lines {
+            let line: Vec<GridPoint> = match direction {
+                Direction::North => (min.r..=max.r).map(|r| GridPoint::new(r, i)).collect(),
+                Direction::South => (min.r..=max.r)
+                    .rev()
+                    .map(|r| GridPoint::new(r, i))
+                    .collect(),
+                Direction::West => (min.c..=max.c).map(|c| GridPoint::new(i, c)).collect(),
+                Direction::East => (min.c..=max.c)
+                    .rev()
+                    .map(|c| GridPoint::new(i, c))
+                    .collect(),
+            };
+            tilt_line(
+                self,
+                &line,
+                |g, p| classify(g.get(&p), &is_movable, &is_blocking),
+                |g, from, to| {
+                    let moving = g.data.remove(&from);
+                    if let Some(empty) = g.data.remove(&to) {
+                        g.data.insert(from, empty);
+                    }
+                    if let Some(v) = moving {
+                        g.data.insert(to, v);
+                    }
+                },
+            );
+        }
+    }
+
+    /// Applies one tilt per direction, in order
+    pub fn spin_cycle(
+        &mut self,
+        directions: &[Direction],
+        is_movable: impl Fn(&T) -> bool,
+        is_blocking: impl Fn(&T) -> bool,
+    ) {
+        for &d in directions {
+            self.tilt(d, &is_movable, &is_blocking);
+        }
+    }
+}
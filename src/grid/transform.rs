@@ -0,0 +1,137 @@
+use crate::{
+    grid::{static_2d::Static2DGrid, view::Static2DGridView},
+    grid_point::unsigned::UGridPoint,
+};
+use ndarray::{ArrayBase, Axis, Ix2, RawData};
+
+/// The eight rotations and reflections of a rectangle. Rotations are clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dihedral {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Mirror along the main diagonal
+    Transpose,
+    /// Mirror along the anti-diagonal
+    AntiTranspose,
+    /// Mirror left to right
+    FlipHorizontal,
+    /// Mirror top to bottom
+    FlipVertical,
+}
+
+impl Dihedral {
+    pub const ALL: [Dihedral; 8] = [
+        Dihedral::Identity,
+        Dihedral::Rotate90,
+        Dihedral::Rotate180,
+        Dihedral::Rotate270,
+        Dihedral::Transpose,
+        Dihedral::AntiTranspose,
+        Dihedral::FlipHorizontal,
+        Dihedral::FlipVertical,
+    ];
+
+    pub fn inverse(self) -> Self {
+        match self {
+            Dihedral::Rotate90 => Dihedral::Rotate270,
+            Dihedral::Rotate270 => Dihedral::Rotate90,
+            other => other,
+        }
+    }
+
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Dihedral::Rotate90
+                | Dihedral::Rotate270
+                | Dihedral::Transpose
+                | Dihedral::AntiTranspose
+        )
+    }
+
+    /// Where the cell at `p` of a `rows` x `columns` grid ends up after the transform
+    pub fn apply(self, p: UGridPoint, rows: usize, columns: usize) -> UGridPoint {
+        let (r, c) = (p.r, p.c);
+        let (last_r, last_c) = (rows - 1, columns - 1);
+        match self {
+            Dihedral::Identity => UGridPoint::new(r, c),
+            Dihedral::Rotate90 => UGridPoint::new(c, last_r - r),
+            Dihedral::Rotate180 => UGridPoint::new(last_r - r, last_c - c),
+            Dihedral::Rotate270 => UGridPoint::new(last_c - c, r),
+            Dihedral::Transpose => UGridPoint::new(c, r),
+            Dihedral::AntiTranspose => UGridPoint::new(last_c - c, last_r - r),
+            Dihedral::FlipHorizontal => UGridPoint::new(r, last_c - c),
+            Dihedral::FlipVertical => UGridPoint::new(last_r - r, c),
+        }
+    }
+
+    /// Reorients an array by adjusting its strides, without moving any elements
+    fn orient<S: RawData>(self, mut a: ArrayBase<S, Ix2>) -> ArrayBase<S, Ix2> {
+        if self.swaps_axes() {
+            a.swap_axes(0, 1);
+        }
+        match self {
+            Dihedral::Identity | Dihedral::Transpose => {}
+            Dihedral::Rotate90 | Dihedral::FlipHorizontal => a.invert_axis(Axis(1)),
+            Dihedral::Rotate270 | Dihedral::FlipVertical => a.invert_axis(Axis(0)),
+            Dihedral::Rotate180 | Dihedral::AntiTranspose => {
+                a.invert_axis(Axis(0));
+                a.invert_axis(Axis(1));
+            }
+        }
+        a
+    }
+}
+
+impl<T> Static2DGrid<T> {
+    pub fn view(&self) -> Static2DGridView<'_, T> {
+        Static2DGridView::new(self.data.view())
+    }
+
+    pub fn transform(self, t: Dihedral) -> Self {
+        Self {
+            data: t.orient(self.data),
+        }
+    }
+
+    pub fn transformed_view(&self, t: Dihedral) -> Static2DGridView<'_, T> {
+        Static2DGridView::new(t.orient(self.data.view()))
+    }
+
+    /// Views of the grid in all eight orientations, starting with the identity
+    pub fn orientations(&self) -> impl Iterator<Item = (Dihedral, Static2DGridView<'_, T>)> {
+        Dihedral::ALL
+            .into_iter()
+            .map(|t| (t, self.transformed_view(t)))
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transform(Dihedral::Rotate90)
+    }
+
+    pub fn rotate_180(self) -> Self {
+        self.transform(Dihedral::Rotate180)
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transform(Dihedral::Rotate270)
+    }
+
+    pub fn transpose(self) -> Self {
+        self.transform(Dihedral::Transpose)
+    }
+
+    pub fn anti_transpose(self) -> Self {
+        self.transform(Dihedral::AntiTranspose)
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        self.transform(Dihedral::FlipHorizontal)
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        self.transform(Dihedral::FlipVertical)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::grid::static_2d::Static2DGrid;
+use ndarray::ArrayView2;
+use std::fmt::{Display, Formatter, Result};
+
+/// Borrowed, read-only window onto the cells of a [`Static2DGrid`]
+pub struct Static2DGridView<'a, T> {
+    pub data: ArrayView2<'a, Option<T>>,
+}
+
+impl<T> Clone for Static2DGridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Static2DGridView<'_, T> {}
+
+impl<'a, T> Static2DGridView<'a, T> {
+    pub fn new(data: ArrayView2<'a, Option<T>>) -> Self {
+        Self { data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.data.shape()[0]
+    }
+
+    pub fn columns(&self) -> usize {
+        self.data.shape()[1]
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
+        self.data.get((r, c))?.as_ref()
+    }
+
+    pub fn in_bounds(&self, r: usize, c: usize) -> bool {
+        r < self.rows() && c < self.columns()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a Option<T>> + use<'a, T> {
+        self.data.into_iter()
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (usize, usize, &'a Option<T>)> + use<'a, T> {
+        let cs = self.columns();
+        self.data
+            .into_iter()
+            .enumerate()
+            .map(move |(i, v)| (i / cs, i % cs, v))
+    }
+
+    pub fn to_grid(&self) -> Static2DGrid<T>
+    where
+        T: Clone,
+    {
+        Static2DGrid {
+            data: self.data.to_owned(),
+        }
+    }
+}
+
+impl<T: Display> Display for Static2DGridView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for r in 0..self.rows() {
+            for c in 0..self.columns() {
+                match self.get(r, c) {
+                    Some(v) => write!(f, "{}", v)?,
+                    None => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
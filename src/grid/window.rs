@@ -0,0 +1,84 @@
+use crate::{
+    grid::{static_2d::Static2DGrid, view::Static2DGridView},
+    grid_point::unsigned::UGridPoint,
+};
+use ndarray::s;
+use std::ops::{Add, Mul, Range};
+
+impl<'a, T> Static2DGridView<'a, T> {
+    /// Borrowed rectangle of the view. Panics if the ranges exceed its bounds.
+    pub fn sub_grid(&self, rows: Range<usize>, columns: Range<usize>) -> Static2DGridView<'a, T> {
+        Static2DGridView::new(self.data.slice_move(s![rows, columns]))
+    }
+
+    /// Every `height` x `width` window with its top-left corner, in row-major order
+    pub fn windows(
+        &self,
+        height: usize,
+        width: usize,
+    ) -> impl Iterator<Item = (UGridPoint, Static2DGridView<'a, T>)> + use<'a, T> {
+        let view = *self;
+        let rs = (self.rows() + 1).saturating_sub(height);
+        let cs = (self.columns() + 1).saturating_sub(width);
+        (0..rs).flat_map(move |r| {
+            (0..cs).map(move |c| {
+                (
+                    UGridPoint::new(r, c),
+                    view.sub_grid(r..r + height, c..c + width),
+                )
+            })
+        })
+    }
+}
+
+impl<T> Static2DGrid<T> {
+    /// Borrowed rectangle of the grid. Panics if the ranges exceed its bounds.
+    pub fn sub_grid(&self, rows: Range<usize>, columns: Range<usize>) -> Static2DGridView<'_, T> {
+        self.view().sub_grid(rows, columns)
+    }
+
+    /// Every `height` x `width` window with its top-left corner, in row-major order
+    pub fn windows(
+        &self,
+        height: usize,
+        width: usize,
+    ) -> impl Iterator<Item = (UGridPoint, Static2DGridView<'_, T>)> {
+        self.view().windows(height, width)
+    }
+
+    /// Grid of `f` applied to every `height` x `width` window, placed at the window's top-left
+    /// corner. The result is smaller than `self` by `height - 1` rows and `width - 1` columns.
+    pub fn apply_kernel<U>(
+        &self,
+        height: usize,
+        width: usize,
+        f: impl Fn(Static2DGridView<'_, T>) -> Option<U>,
+    ) -> Static2DGrid<U> {
+        let mut result = Static2DGrid::new(
+            (self.rows() + 1).saturating_sub(height),
+            (self.columns() + 1).saturating_sub(width),
+        );
+        for (p, window) in self.windows(height, width) {
+            if let Some(v) = f(window) {
+                result.set(p.r, p.c, v);
+            }
+        }
+        result
+    }
+
+    /// Weighted sum of every window under `kernel`, without flipping the kernel. Cells absent
+    /// from either grid contribute nothing.
+    pub fn convolve<K>(&self, kernel: &Static2DGrid<K>) -> Static2DGrid<T>
+    where
+        T: Mul<K, Output = T> + Add<Output = T> + Default + Copy,
+        K: Copy,
+    {
+        self.apply_kernel(kernel.rows(), kernel.columns(), |window| {
+            let sum = kernel
+                .indexed_iter()
+                .filter_map(|(r, c, k)| Some(*window.get(r, c)? * (*k)?))
+                .fold(T::default(), |acc, v| acc + v);
+            Some(sum)
+        })
+    }
+}
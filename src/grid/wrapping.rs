@@ -0,0 +1,123 @@
+use crate::{
+    grid::{Grid, Neighborhood, static_2d::Static2DGrid},
+    grid_point::{signed::GridPoint, unsigned::UGridPoint},
+};
+
+fn neighbor_points(p: &GridPoint, neighborhood: Neighborhood) -> Vec<GridPoint> {
+    match neighborhood {
+        Neighborhood::Cardinal => p.cardinal_neighbors().to_vec(),
+        Neighborhood::All => p.all_neighbors().to_vec(),
+    }
+}
+
+/// Read-only view of a [`Static2DGrid`] whose opposite edges are glued together. Every signed
+/// point maps onto the grid with Euclidean modulo, and neighbors are reported already wrapped.
+#[derive(Clone, Copy)]
+pub struct ToroidalView<'a, T> {
+    pub grid: &'a Static2DGrid<T>,
+}
+
+impl<'a, T> ToroidalView<'a, T> {
+    pub fn new(grid: &'a Static2DGrid<T>) -> Self {
+        assert!(
+            grid.rows() > 0 && grid.columns() > 0,
+            "ToroidalView requires a non-empty grid"
+        );
+        Self { grid }
+    }
+
+    pub fn wrap(&self, p: GridPoint) -> UGridPoint {
+        UGridPoint::new(
+            p.r.rem_euclid(self.grid.rows() as isize) as usize,
+            p.c.rem_euclid(self.grid.columns() as isize) as usize,
+        )
+    }
+
+    pub fn get(&self, p: GridPoint) -> Option<&'a T> {
+        let p = self.wrap(p);
+        self.grid.get(p.r, p.c)
+    }
+}
+
+impl<T> Grid for ToroidalView<'_, T> {
+    type Point = GridPoint;
+    type Value = T;
+
+    fn get(&self, p: &GridPoint) -> Option<&T> {
+        ToroidalView::get(self, *p)
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        Grid::indexed_iter(self.grid).map(|(p, v)| (p.into(), v))
+    }
+
+    fn adjacent(&self, p: &GridPoint, neighborhood: Neighborhood) -> Vec<GridPoint> {
+        neighbor_points(p, neighborhood)
+            .into_iter()
+            .map(|n| self.wrap(n).into())
+            .collect()
+    }
+}
+
+/// Read-only view of a [`Static2DGrid`] repeated infinitely in every direction. Points keep
+/// their signed coordinates, so the same cell of different copies stays distinct.
+#[derive(Clone, Copy)]
+pub struct TiledView<'a, T> {
+    pub grid: &'a Static2DGrid<T>,
+}
+
+impl<'a, T> TiledView<'a, T> {
+    pub fn new(grid: &'a Static2DGrid<T>) -> Self {
+        assert!(
+            grid.rows() > 0 && grid.columns() > 0,
+            "TiledView requires a non-empty grid"
+        );
+        Self { grid }
+    }
+
+    /// Position of `p` inside its copy of the grid
+    pub fn wrap(&self, p: GridPoint) -> UGridPoint {
+        ToroidalView::new(self.grid).wrap(p)
+    }
+
+    /// Which copy of the grid `p` lies in, with the original at (0, 0)
+    pub fn tile(&self, p: GridPoint) -> GridPoint {
+        GridPoint::new(
+            p.r.div_euclid(self.grid.rows() as isize),
+            p.c.div_euclid(self.grid.columns() as isize),
+        )
+    }
+
+    pub fn get(&self, p: GridPoint) -> Option<&'a T> {
+        let p = self.wrap(p);
+        self.grid.get(p.r, p.c)
+    }
+}
+
+/// `indexed_iter` only covers the original copy, since the view itself is unbounded
+impl<T> Grid for TiledView<'_, T> {
+    type Point = GridPoint;
+    type Value = T;
+
+    fn get(&self, p: &GridPoint) -> Option<&T> {
+        TiledView::get(self, *p)
+    }
+
+    fn indexed_iter(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        Grid::indexed_iter(self.grid).map(|(p, v)| (p.into(), v))
+    }
+
+    fn adjacent(&self, p: &GridPoint, neighborhood: Neighborhood) -> Vec<GridPoint> {
+        neighbor_points(p, neighborhood)
+    }
+}
+
+impl<T> Static2DGrid<T> {
+    pub fn toroidal(&self) -> ToroidalView<'_, T> {
+        ToroidalView::new(self)
+    }
+
+    pub fn tiled(&self) -> TiledView<'_, T> {
+        TiledView::new(self)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::grid::GridMut;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Deterministic hash of a single cell, independent of all other cells
+pub fn cell_hash<P: Hash, V: Hash>(p: &P, v: &V) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    p.hash(&mut hasher);
+    v.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Grid wrapper that keeps the XOR of every [`cell_hash`] up to date on each write, so hashing
+/// the whole grid is O(1). Mutable access goes through [`Self::set`] and [`Self::remove`] only.
+#[derive(Clone)]
+pub struct ZobristGrid<G> {
+    grid: G,
+    hash: u64,
+}
+
+impl<G> ZobristGrid<G>
+where
+    G: GridMut,
+    G::Value: Hash,
+{
+    pub fn new(grid: G) -> Self {
+        let hash = grid
+            .indexed_iter()
+            .fold(0, |acc, (p, v)| acc ^ cell_hash(&p, v));
+        Self { grid, hash }
+    }
+
+    pub fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    pub fn hash_value(&self) -> u64 {
+        self.hash
+    }
+
+    pub fn get(&self, p: &G::Point) -> Option<&G::Value> {
+        self.grid.get(p)
+    }
+
+    pub fn set(&mut self, p: G::Point, v: G::Value) -> Option<G::Value> {
+        self.hash ^= cell_hash(&p, &v);
+        let old = self.grid.set(p.clone(), v);
+        if let Some(old) = &old {
+            self.hash ^= cell_hash(&p, old);
+        }
+        old
+    }
+
+    pub fn remove(&mut self, p: &G::Point) -> Option<G::Value> {
+        let old = self.grid.remove(p);
+        if let Some(old) = &old {
+            self.hash ^= cell_hash(p, old);
+        }
+        old
+    }
+}
+
+impl<G: PartialEq> PartialEq for ZobristGrid<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.grid == other.grid
+    }
+}
+
+impl<G: Eq> Eq for ZobristGrid<G> {}
+
+impl<G> Hash for ZobristGrid<G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
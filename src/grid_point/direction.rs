@@ -0,0 +1,65 @@
+use crate::grid_point::signed::GridPoint;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// Row and column offset of one step, with north pointing towards row 0
+    pub fn delta(self) -> GridPoint {
+        match self {
+            Direction::North => GridPoint::new(-1, 0),
+            Direction::East => GridPoint::new(0, 1),
+            Direction::South => GridPoint::new(1, 0),
+            Direction::West => GridPoint::new(0, -1),
+        }
+    }
+}
+
+impl From<Direction> for GridPoint {
+    fn from(d: Direction) -> Self {
+        d.delta()
+    }
+}
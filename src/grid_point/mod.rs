@@ -1,3 +1,4 @@
+pub mod direction;
 pub mod hex;
 pub mod n_dimensional;
 pub mod signed;
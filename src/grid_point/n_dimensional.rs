@@ -1,6 +1,6 @@
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct NDGridPoint {
     pub coords: Vec<isize>,
 }
@@ -21,6 +21,19 @@ impl NDGridPoint {
         }
     }
 
+    pub fn cardinal_neighbors(&self) -> Vec<NDGridPoint> {
+        let mut result = Vec::with_capacity(2 * self.ndim());
+        let mut deltas = vec![0; self.ndim()];
+        for i in 0..self.ndim() {
+            for d in [-1, 1] {
+                deltas[i] = d;
+                result.push(self.offset(&deltas));
+            }
+            deltas[i] = 0;
+        }
+        result
+    }
+
     pub fn neighbors(&self) -> Vec<NDGridPoint> {
         let ndim = self.ndim();
         let mut result = Vec::new();
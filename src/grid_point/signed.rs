@@ -1,6 +1,7 @@
+use crate::grid_point::unsigned::UGridPoint;
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
-#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
 pub struct GridPoint {
     pub r: isize,
     pub c: isize,
@@ -11,6 +12,10 @@ impl GridPoint {
         Self { r, c }
     }
 
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.r.abs_diff(other.r) + self.c.abs_diff(other.c)
+    }
+
     pub fn cardinal_neighbors(&self) -> [GridPoint; 4] {
         let directions = [
             GridPoint::new(-1, 0),
@@ -142,3 +147,9 @@ impl Rem<GridPoint> for isize {
         GridPoint::new(self % rhs.r, self % rhs.c)
     }
 }
+
+impl From<UGridPoint> for GridPoint {
+    fn from(p: UGridPoint) -> Self {
+        Self::new(p.r as isize, p.c as isize)
+    }
+}
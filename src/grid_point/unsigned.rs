@@ -1,6 +1,6 @@
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
-#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
 pub struct UGridPoint {
     pub r: usize,
     pub c: usize,
@@ -11,6 +11,10 @@ impl UGridPoint {
         Self { r, c }
     }
 
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.r.abs_diff(other.r) + self.c.abs_diff(other.c)
+    }
+
     pub fn cardinal_neighbors(&self) -> [UGridPoint; 4] {
         let directions = [
             UGridPoint::new(usize::MAX, 0),
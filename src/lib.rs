@@ -1,7 +1,9 @@
+pub mod cycle;
 pub mod grid;
 pub mod grid_point;
 pub mod helper;
 pub mod line;
 pub mod math;
 pub mod point;
+pub mod search;
 pub mod tree;
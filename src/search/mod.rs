@@ -0,0 +1,291 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::ControlFlow,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult<S> {
+    pub cost: u64,
+    /// States from the start to the goal, both included
+    pub path: Vec<S>,
+    /// Number of distinct states discovered, or expansions for IDA*
+    pub visited: usize,
+}
+
+fn reconstruct<S: Clone>(nodes: &[(S, Option<usize>)], mut i: usize) -> Vec<S> {
+    let mut path = vec![nodes[i].0.clone()];
+    while let Some(parent) = nodes[i].1 {
+        path.push(nodes[parent].0.clone());
+        i = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Fewest-steps search where every transition costs 1
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl Fn(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![(start, None)];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&nodes[i].0) {
+            let path = reconstruct(&nodes, i);
+            return Some(SearchResult {
+                cost: path.len() as u64 - 1,
+                path,
+                visited: nodes.len(),
+            });
+        }
+        for n in successors(&nodes[i].0) {
+            if index.contains_key(&n) {
+                continue;
+            }
+            index.insert(n.clone(), nodes.len());
+            queue.push_back(nodes.len());
+            nodes.push((n, Some(i)));
+        }
+    }
+
+    None
+}
+
+/// Cheapest-path search where `successors` yields each next state with the cost of reaching it
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl Fn(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, successors, is_goal, |_| 0)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate the remaining cost
+pub fn astar<S, I>(
+    start: S,
+    successors: impl Fn(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+    heuristic: impl Fn(&S) -> u64,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![(start, None)];
+    let mut costs = vec![0];
+
+    while let Some(Reverse((_, d, i))) = heap.pop() {
+        if costs[i] < d {
+            continue;
+        }
+        if is_goal(&nodes[i].0) {
+            return Some(SearchResult {
+                cost: d,
+                path: reconstruct(&nodes, i),
+                visited: nodes.len(),
+            });
+        }
+        for (n, step) in successors(&nodes[i].0) {
+            let nd = d + step;
+            let j = match index.get(&n) {
+                Some(&j) if costs[j] <= nd => continue,
+                Some(&j) => {
+                    costs[j] = nd;
+                    nodes[j].1 = Some(i);
+                    j
+                }
+                None => {
+                    let j = nodes.len();
+                    index.insert(n.clone(), j);
+                    nodes.push((n, Some(i)));
+                    costs.push(nd);
+                    j
+                }
+            };
+            heap.push(Reverse((nd + heuristic(&nodes[j].0), nd, j)));
+        }
+    }
+
+    None
+}
+
+struct IdaStar<S, F, G, H> {
+    path: Vec<S>,
+    on_path: HashSet<S>,
+    visited: usize,
+    successors: F,
+    is_goal: G,
+    heuristic: H,
+}
+
+impl<S, I, F, G, H> IdaStar<S, F, G, H>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+    F: Fn(&S) -> I,
+    G: Fn(&S) -> bool,
+    H: Fn(&S) -> u64,
+{
+    /// Breaks with the goal cost, or continues with the smallest f-value that exceeded `bound`
+    fn search(&mut self, cost: u64, bound: u64) -> ControlFlow<u64, Option<u64>> {
+        self.visited += 1;
+        let state = self.path.last().unwrap().clone();
+        let f = cost + (self.heuristic)(&state);
+        if f > bound {
+            return ControlFlow::Continue(Some(f));
+        }
+        if (self.is_goal)(&state) {
+            return ControlFlow::Break(cost);
+        }
+        let mut next_bound: Option<u64> = None;
+        for (n, step) in (self.successors)(&state) {
+            if !self.on_path.insert(n.clone()) {
+                continue;
+            }
+            self.path.push(n);
+            match self.search(cost + step, bound) {
+                ControlFlow::Break(total) => return ControlFlow::Break(total),
+                ControlFlow::Continue(Some(t)) => {
+                    next_bound = Some(next_bound.map_or(t, |b| b.min(t)))
+                }
+                ControlFlow::Continue(None) => {}
+            }
+            let n = self.path.pop().unwrap();
+            self.on_path.remove(&n);
+        }
+        ControlFlow::Continue(next_bound)
+    }
+}
+
+/// Iterative-deepening A*, trading repeated work for memory proportional to the path length
+pub fn ida_star<S, I>(
+    start: S,
+    successors: impl Fn(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+    heuristic: impl Fn(&S) -> u64,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut bound = heuristic(&start);
+    let mut ida = IdaStar {
+        path: vec![start.clone()],
+        on_path: HashSet::from([start]),
+        visited: 0,
+        successors,
+        is_goal,
+        heuristic,
+    };
+
+    loop {
+        match ida.search(0, bound) {
+            ControlFlow::Break(cost) => {
+                return Some(SearchResult {
+                    cost,
+                    path: ida.path,
+                    visited: ida.visited,
+                });
+            }
+            ControlFlow::Continue(Some(next)) => bound = next,
+            ControlFlow::Continue(None) => return None,
+        }
+    }
+}
+
+/// Fewest-steps search growing one frontier from `start` and one from `goal` until they meet.
+/// `predecessors` yields the states that lead into a given state; pass `successors` again when
+/// transitions are reversible.
+pub fn bidirectional_bfs<S, I, J>(
+    start: S,
+    goal: S,
+    successors: impl Fn(&S) -> I,
+    predecessors: impl Fn(&S) -> J,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+    J: IntoIterator<Item = S>,
+{
+    if start == goal {
+        return Some(SearchResult {
+            cost: 0,
+            path: vec![start],
+            visited: 1,
+        });
+    }
+
+    // Each state maps to the neighbouring state towards its own root and its depth from it
+    let mut forward: HashMap<S, (Option<S>, usize)> = HashMap::from([(start.clone(), (None, 0))]);
+    let mut backward: HashMap<S, (Option<S>, usize)> = HashMap::from([(goal.clone(), (None, 0))]);
+    let mut forward_frontier = vec![start];
+    let mut backward_frontier = vec![goal];
+
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        let expand_forward = forward_frontier.len() <= backward_frontier.len();
+        let (frontier, own, other) = if expand_forward {
+            (&mut forward_frontier, &mut forward, &backward)
+        } else {
+            (&mut backward_frontier, &mut backward, &forward)
+        };
+
+        let mut next = Vec::new();
+        let mut meet: Option<(S, usize)> = None;
+        for s in frontier.drain(..) {
+            let depth = own[&s].1 + 1;
+            let neighbors: Vec<S> = if expand_forward {
+                successors(&s).into_iter().collect()
+            } else {
+                predecessors(&s).into_iter().collect()
+            };
+            for n in neighbors {
+                if own.contains_key(&n) {
+                    continue;
+                }
+                own.insert(n.clone(), (Some(s.clone()), depth));
+                if let Some(&(_, other_depth)) = other.get(&n)
+                    && meet
+                        .as_ref()
+                        .is_none_or(|(_, best)| depth + other_depth < *best)
+                {
+                    meet = Some((n.clone(), depth + other_depth));
+                }
+                next.push(n);
+            }
+        }
+        *frontier = next;
+
+        if let Some((middle, cost)) = meet {
+            let mut path = vec![middle.clone()];
+            while let Some((Some(parent), _)) = forward.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            while let Some((Some(child), _)) = backward.get(path.last().unwrap()) {
+                path.push(child.clone());
+            }
+            return Some(SearchResult {
+                cost: cost as u64,
+                path,
+                visited: forward.len() + backward.len(),
+            });
+        }
+    }
+
+    None
+}